@@ -0,0 +1,82 @@
+use common::{Answer, Error, Result, Solution};
+
+fn parse_numbers(input: &str) -> Result<Vec<Vec<u32>>> {
+    let numbers: Vec<Vec<u32>> = input
+        .split("\n\n")
+        .map(|chunk| {
+            chunk
+                .lines()
+                .map(|l| {
+                    l.parse::<u32>()
+                        .map_err(|_| Error::parse(Day01::DAY, input, l, "a number of calories"))
+                })
+                .collect()
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(numbers)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Vec<u32>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_numbers(input)
+    }
+
+    fn part1(numbers: &Self::Input, _: &()) -> Result<Answer> {
+        let max = numbers
+            .iter()
+            .map(|n| {
+                let s: u32 = n.iter().sum();
+                s
+            })
+            .max()
+            .ok_or_else(|| Error::solve(Self::DAY, "there are no elves"))?;
+
+        Ok(max.into())
+    }
+
+    fn part2(numbers: &Self::Input, _: &()) -> Result<Answer> {
+        let mut elves: Vec<u32> = numbers
+            .iter()
+            .map(|n| {
+                let s: u32 = n.iter().sum();
+                s
+            })
+            .collect();
+
+        elves.sort_by(|a, b| b.cmp(a));
+
+        let top_3: u32 = elves.iter().take(3).sum();
+
+        Ok(top_3.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day01::part1(&Day01::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day01::part2(&Day01::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1("1000\n2000\n\n30x0").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 4, column 1: expected a number of calories, found \"30x0\""
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use common::{Answer, Error, Result, Solution};
+
+#[derive(Copy, Clone)]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissor,
+}
+
+#[derive(Copy, Clone)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+fn score(opp: Move, myself: Move) -> u32 {
+    match (opp, myself) {
+        (Move::Rock, Move::Rock) => 3 + 1,
+        (Move::Rock, Move::Paper) => 6 + 2,
+        (Move::Rock, Move::Scissor) => 3,
+        (Move::Paper, Move::Rock) => 1,
+        (Move::Paper, Move::Paper) => 3 + 2,
+        (Move::Paper, Move::Scissor) => 6 + 3,
+        (Move::Scissor, Move::Rock) => 6 + 1,
+        (Move::Scissor, Move::Paper) => 2,
+        (Move::Scissor, Move::Scissor) => 3 + 3,
+    }
+}
+
+fn parse_rounds(input: &str) -> Result<Vec<(Move, Column)>> {
+    input
+        .lines()
+        .map(|moves| {
+            let mut encrypted = moves.split_whitespace();
+            // parse opponent
+            let opp = match encrypted.next() {
+                Some("A") => Move::Rock,
+                Some("B") => Move::Paper,
+                Some("C") => Move::Scissor,
+                other => {
+                    let at = other.unwrap_or(&moves[moves.len()..]);
+                    return Err(Error::parse(Day02::DAY, input, at, "A, B or C"));
+                }
+            };
+            // parse the second column, its meaning depends on the puzzle
+            let column = match encrypted.next() {
+                Some("X") => Column::X,
+                Some("Y") => Column::Y,
+                Some("Z") => Column::Z,
+                other => {
+                    let at = other.unwrap_or(&moves[moves.len()..]);
+                    return Err(Error::parse(Day02::DAY, input, at, "X, Y or Z"));
+                }
+            };
+            // return the move tuple
+            Ok((opp, column))
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(Move, Column)>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_rounds(input)
+    }
+
+    fn part1(rounds: &Self::Input, _: &()) -> Result<Answer> {
+        let points: u32 = rounds
+            .iter()
+            .map(|&(opp, column)| {
+                // the column is my move
+                let myself = match column {
+                    Column::X => Move::Rock,
+                    Column::Y => Move::Paper,
+                    Column::Z => Move::Scissor,
+                };
+                score(opp, myself)
+            })
+            .sum();
+
+        Ok(points.into())
+    }
+
+    fn part2(rounds: &Self::Input, _: &()) -> Result<Answer> {
+        let points: u32 = rounds
+            .iter()
+            .map(|&(opp, column)| {
+                // the column is the outcome of the round
+                let myself = match column {
+                    Column::X => match opp {
+                        Move::Rock => Move::Scissor,
+                        Move::Paper => Move::Rock,
+                        Move::Scissor => Move::Paper,
+                    },
+                    Column::Y => opp,
+                    Column::Z => match opp {
+                        Move::Rock => Move::Paper,
+                        Move::Paper => Move::Scissor,
+                        Move::Scissor => Move::Rock,
+                    },
+                };
+                score(opp, myself)
+            })
+            .sum();
+
+        Ok(points.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day02::part1(&Day02::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day02::part2(&Day02::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1("A Y\nB").unwrap_err();
+        assert_eq!(err.location(), Some((2, 2)));
+
+        let err = puzzle_2("A Y\nD X").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 2, line 2, column 1: expected A, B or C, found \"D\""
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use common::{Answer, Error, Result, Solution};
+
+fn priority(item: char) -> u32 {
+    match item {
+        'a'..='z' => (item as u32) - 96,
+        'A'..='Z' => (item as u32) - 38,
+        _ => unreachable!("items are checked by the parser"),
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<char>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|l| match l.find(|c: char| !c.is_ascii_alphabetic()) {
+                Some(i) => Err(Error::parse(Self::DAY, input, &l[i..], "an item type")),
+                None => Ok(l.chars().collect()),
+            })
+            .collect()
+    }
+
+    fn part1(rucksacks: &Self::Input, _: &()) -> Result<Answer> {
+        let priorities: Vec<u32> = rucksacks
+            .iter()
+            .map(|rucksack| {
+                let count = rucksack.len();
+                for i in 0..count / 2 {
+                    for j in (count / 2)..count {
+                        if rucksack[i] == rucksack[j] {
+                            return priority(rucksack[i]);
+                        }
+                    }
+                }
+                0
+            })
+            .collect();
+
+        let sum: u32 = priorities.iter().sum();
+
+        Ok(sum.into())
+    }
+
+    fn part2(rucksacks: &Self::Input, _: &()) -> Result<Answer> {
+        if rucksacks.len() % 3 != 0 {
+            return Err(Error::solve(
+                Self::DAY,
+                "the elves can't be split into groups of three",
+            ));
+        }
+
+        let priorities: Vec<u32> = rucksacks
+            .chunks(3)
+            .map(|group| {
+                let a = &group[0];
+                let b = &group[1];
+                let c = &group[2];
+
+                for item in a {
+                    if b.contains(item) && c.contains(item) {
+                        return priority(*item);
+                    }
+                }
+
+                0
+            })
+            .collect();
+
+        let sum: u32 = priorities.iter().sum();
+
+        Ok(sum.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day03::part1(&Day03::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day03::part2(&Day03::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRj1zjG").unwrap_err();
+        assert_eq!(err.location(), Some((2, 9)));
+    }
+}
@@ -0,0 +1,112 @@
+use common::{Answer, Error, Result, Solution};
+
+pub struct CleaningSection(u32, u32);
+
+impl CleaningSection {
+    fn contains(&self, other: &Self) -> bool {
+        if self.0 <= other.0 && self.1 >= other.1 {
+            return true;
+        }
+
+        false
+    }
+
+    fn overlap(&self, other: &Self) -> bool {
+        if (self.0 <= other.0 && self.1 >= other.0) || (other.0 <= self.0 && other.1 >= self.0) {
+            return true;
+        }
+
+        false
+    }
+}
+
+fn parse_id(input: &str, id: &str) -> Result<u32> {
+    id.parse::<u32>()
+        .map_err(|_| Error::parse(Day04::DAY, input, id, "a section id"))
+}
+
+fn parse_elf(input: &str, elf: &str) -> Result<CleaningSection> {
+    let (start, end) = elf
+        .split_once('-')
+        .ok_or_else(|| Error::parse(Day04::DAY, input, &elf[elf.len()..], "'-'"))?;
+
+    Ok(CleaningSection(
+        parse_id(input, start)?,
+        parse_id(input, end)?,
+    ))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<(CleaningSection, CleaningSection)>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                let (first, second) = line
+                    .split_once(',')
+                    .ok_or_else(|| Error::parse(Self::DAY, input, &line[line.len()..], "','"))?;
+
+                Ok((parse_elf(input, first)?, parse_elf(input, second)?))
+            })
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Input, _: &()) -> Result<Answer> {
+        let containments = pairs
+            .iter()
+            .map(|pair| {
+                if pair.0.contains(&pair.1) || pair.1.contains(&pair.0) {
+                    return 1;
+                }
+                0
+            })
+            .sum::<u32>();
+
+        Ok(containments.into())
+    }
+
+    fn part2(pairs: &Self::Input, _: &()) -> Result<Answer> {
+        let overlaps = pairs
+            .iter()
+            .map(|pair| {
+                if pair.0.overlap(&pair.1) {
+                    return 1;
+                }
+                0
+            })
+            .sum::<u32>();
+
+        Ok(overlaps.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day04::part1(&Day04::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day04::part2(&Day04::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1("2-4,6-8\n2-3;4-5").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 4, line 2, column 8: expected ',', found end of input"
+        );
+
+        let err = puzzle_2("2-4,6-a").unwrap_err();
+        assert_eq!(err.location(), Some((1, 7)));
+    }
+}
@@ -0,0 +1,204 @@
+use common::{Answer, Error, Result, Solution};
+
+fn parse_stacks(input: &str, drawing: &str) -> Result<Vec<Vec<char>>> {
+    // traverse the stack rows from the bottom up
+    let mut lines = drawing.lines().rev();
+
+    // use the bottom row for counting the stacks
+    let stack_count = lines
+        .next()
+        .ok_or_else(|| Error::parse(Day05::DAY, input, drawing, "a drawing of the stacks"))?
+        .matches(char::is_numeric)
+        .count();
+
+    let mut stacks: Vec<Vec<char>> = Vec::new();
+    for _ in 0..stack_count {
+        stacks.push(Vec::new());
+    }
+
+    // traverse the other rows
+    for row in lines {
+        for (i, (pos, item)) in row.char_indices().skip(1).step_by(4).enumerate() {
+            if item == ' ' {
+                continue;
+            }
+            if i >= stack_count || !item.is_ascii_uppercase() {
+                return Err(Error::parse(Day05::DAY, input, &row[pos..], "a crate"));
+            }
+            stacks[i].push(item);
+        }
+    }
+
+    Ok(stacks)
+}
+
+#[derive(Debug)]
+pub struct Move {
+    amount: u32,
+    source: usize,
+    target: usize,
+}
+
+fn parse_moves(input: &str, moves: &str, stack_count: usize) -> Result<Vec<Move>> {
+    let moves = moves
+        .lines()
+        .map(|line| {
+            let mut words = line.split(' ');
+            let end = &line[line.len()..];
+
+            let mut numbers = [0; 3];
+            for (keyword, number) in ["move", "from", "to"].into_iter().zip(numbers.iter_mut()) {
+                match words.next() {
+                    Some(w) if w == keyword => {}
+                    w => {
+                        let expected = format!("\"{}\"", keyword);
+                        return Err(Error::parse(Day05::DAY, input, w.unwrap_or(end), expected));
+                    }
+                }
+
+                let w = words.next().unwrap_or(end);
+                *number = w
+                    .parse::<usize>()
+                    .map_err(|_| Error::parse(Day05::DAY, input, w, "a number"))?;
+
+                // stacks are numbered starting at 1
+                if keyword != "move" && !(1..=stack_count).contains(number) {
+                    return Err(Error::parse(Day05::DAY, input, w, "a stack number"));
+                }
+            }
+
+            if let Some(w) = words.next() {
+                return Err(Error::parse(Day05::DAY, input, w, "end of line"));
+            }
+
+            Ok(Move {
+                amount: numbers[0] as u32,
+                source: numbers[1] - 1,
+                target: numbers[2] - 1,
+            })
+        })
+        .collect::<Result<Vec<Move>>>()?;
+
+    Ok(moves)
+}
+
+fn empty_stack(stack: usize) -> Error {
+    Error::solve(
+        Day05::DAY,
+        format!("stack {} runs out of crates", stack + 1),
+    )
+}
+
+pub struct Procedure {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Procedure;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (stacks, moves) = input.split_once("\n\n").ok_or_else(|| {
+            Error::parse(Self::DAY, input, &input[input.len()..], "an empty line")
+        })?;
+        let stacks = parse_stacks(input, stacks)?;
+        let moves = parse_moves(input, moves, stacks.len())?;
+
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part1(procedure: &Self::Input, _: &()) -> Result<Answer> {
+        let mut stacks = procedure.stacks.clone();
+
+        for m in procedure.moves.iter() {
+            for _ in 0..m.amount {
+                let src = stacks[m.source]
+                    .pop()
+                    .ok_or_else(|| empty_stack(m.source))?;
+                stacks[m.target].push(src);
+            }
+        }
+
+        let tops = stacks.iter().filter_map(|s| s.last()).collect::<String>();
+
+        Ok(tops.into())
+    }
+
+    fn part2(procedure: &Self::Input, _: &()) -> Result<Answer> {
+        let mut stacks = procedure.stacks.clone();
+
+        for m in procedure.moves.iter() {
+            // get the soruce stack
+            let stack = &mut stacks[m.source];
+
+            let pos = stack
+                .len()
+                .checked_sub(m.amount as usize)
+                .ok_or_else(|| empty_stack(m.source))?;
+            // pull the crates from the stack
+            let mut items = stack.drain(pos..).collect::<Vec<char>>();
+
+            // get the target stack
+            let stack = &mut stacks[m.target];
+
+            // put the items on the stack
+            stack.append(&mut items);
+        }
+
+        let tops = stacks.iter().filter_map(|s| s.last()).collect::<String>();
+
+        Ok(tops.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day05::part1(&Day05::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day05::part2(&Day05::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/2022/day05/example.txt");
+
+    #[test]
+    fn line_endings() {
+        let input = INPUT.replace('\n', "\r\n") + "\r\n";
+        let result = puzzle_1(&input).unwrap();
+        assert_eq!(result, Answer::from("CMZ"));
+    }
+
+    #[test]
+    fn truncated() {
+        let input = INPUT.trim_end();
+        let input = &input[..input.len() - 5];
+        let err = puzzle_1(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 5, line 9, column 14: expected \"to\", found end of input"
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let input = INPUT.replace("move 2 from 2", "move 2 from 4");
+        let err = puzzle_1(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 5, line 8, column 13: expected a stack number, found \"4\""
+        );
+
+        let input = INPUT.replace("move 3", "move 4");
+        let err = puzzle_2(&input).unwrap_err();
+        assert_eq!(err.to_string(), "day 5: stack 1 runs out of crates");
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+
+use common::{Answer, Error, Result, Solution};
+
+const PACKET_LENGTH: usize = 4;
+const MESSAGE_LENGTH: usize = 14;
+
+fn find_marker(sequence: &[char], length: usize) -> Result<usize> {
+    let mut index = None;
+
+    for i in length..sequence.len() {
+        // get the last characters from the sequence
+        // and collect them in a set
+        let set = sequence
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, c)| {
+                if idx >= i - length && idx < i {
+                    Some(*c)
+                } else {
+                    None
+                }
+            })
+            .collect::<HashSet<char>>();
+
+        // check the set length to find if there were duplicates
+        if set.len() == length {
+            index = Some(i);
+            break;
+        }
+    }
+
+    index.ok_or_else(|| {
+        let message = format!("no marker of {} distinct characters", length);
+        Error::solve(Day06::DAY, message)
+    })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        match input.find(|c: char| !c.is_ascii_lowercase()) {
+            Some(i) => Err(Error::parse(
+                Self::DAY,
+                input,
+                &input[i..],
+                "a lowercase letter",
+            )),
+            None => Ok(input.chars().collect()),
+        }
+    }
+
+    fn part1(sequence: &Self::Input, _: &()) -> Result<Answer> {
+        Ok(find_marker(sequence, PACKET_LENGTH)?.into())
+    }
+
+    fn part2(sequence: &Self::Input, _: &()) -> Result<Answer> {
+        Ok(find_marker(sequence, MESSAGE_LENGTH)?.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day06::part1(&Day06::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day06::part2(&Day06::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/2022/day06/example.txt");
+
+    #[test]
+    fn line_endings() {
+        // a trailing newline is not part of the sequence
+        let result = puzzle_1(&format!("{}\n", INPUT)).unwrap();
+        assert_eq!(result, Answer::Integer(7));
+
+        let result = puzzle_1(&format!("\u{feff}{}\r\n", INPUT)).unwrap();
+        assert_eq!(result, Answer::Integer(7));
+    }
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1("mjqjp3mgbl").unwrap_err();
+        assert_eq!(err.location(), Some((1, 6)));
+
+        let err = puzzle_2("mjqjpqmgbl").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 6: no marker of 14 distinct characters"
+        );
+    }
+}
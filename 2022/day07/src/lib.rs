@@ -0,0 +1,195 @@
+use common::{Answer, Error, Result, Solution};
+
+#[derive(Debug)]
+enum Cmd {
+    Ls,
+    Cd(String),
+    Dir(String),
+    File((usize, String)),
+}
+
+fn parse_commands(input: &str) -> Result<Vec<Cmd>> {
+    input
+        .lines()
+        .map(|l| {
+            if l == "$ ls" {
+                return Ok(Cmd::Ls);
+            } else if let Some(name) = l.strip_prefix("$ cd ") {
+                return Ok(Cmd::Cd(name.to_string()));
+            } else if let Some(name) = l.strip_prefix("dir ") {
+                return Ok(Cmd::Dir(name.to_string()));
+            } else if l.starts_with('$') {
+                return Err(Error::parse(Day07::DAY, input, l, "a cd or ls command"));
+            }
+
+            // file
+            let (size, name) = l
+                .split_once(' ')
+                .ok_or_else(|| Error::parse(Day07::DAY, input, l, "a directory or file"))?;
+
+            let size = size
+                .parse::<usize>()
+                .map_err(|_| Error::parse(Day07::DAY, input, size, "a file size"))?;
+            Ok(Cmd::File((size, name.to_string())))
+        })
+        .collect::<Result<Vec<Cmd>>>()
+}
+
+#[derive(Debug)]
+pub struct FsEntry {
+    path: Vec<String>,
+    dir: bool,
+    size: usize,
+}
+
+fn parse_entries(commands: Vec<Cmd>) -> Vec<FsEntry> {
+    let mut current_path = vec![];
+    let mut entries = vec![];
+
+    for c in commands {
+        match c {
+            Cmd::Cd(n) => {
+                if n == ".." {
+                    current_path.pop();
+                } else {
+                    current_path.push(n);
+                }
+            }
+            Cmd::File((s, n)) => {
+                let mut path = current_path.clone();
+                path.push(n);
+                entries.push(FsEntry {
+                    path,
+                    dir: false,
+                    size: s,
+                });
+            }
+            Cmd::Dir(n) => {
+                let mut path = current_path.clone();
+                path.push(n);
+                entries.push(FsEntry {
+                    path,
+                    dir: true,
+                    size: 0,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    entries
+}
+
+fn dir_sizes(entries: &[FsEntry]) -> Vec<usize> {
+    entries
+        .iter()
+        .filter_map(|e| {
+            if !e.dir {
+                return None;
+            }
+
+            let parent_path = e.path.as_slice();
+            // get the directory size from all descendent files
+            let size = entries
+                .iter()
+                .filter_map(|entry| {
+                    // ignore folders and files without the parent path
+                    if entry.dir || !entry.path.starts_with(parent_path) {
+                        return None;
+                    }
+
+                    Some(entry.size)
+                })
+                .sum::<usize>();
+
+            Some(size)
+        })
+        .collect()
+}
+
+const TOTAL: usize = 70000000;
+const REQUIRED: usize = 30000000;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<FsEntry>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let commands = parse_commands(input)?;
+        Ok(parse_entries(commands))
+    }
+
+    fn part1(entries: &Self::Input, _: &()) -> Result<Answer> {
+        let dir_sizes = dir_sizes(entries);
+
+        // sum all folder sizes which fulfill the requirements
+        let result = dir_sizes
+            .iter()
+            .filter(|s| {
+                if **s <= 100000 {
+                    return true;
+                }
+                false
+            })
+            .sum::<usize>();
+
+        Ok(result.into())
+    }
+
+    fn part2(entries: &Self::Input, _: &()) -> Result<Answer> {
+        let used_space = entries.iter().map(|e| e.size).sum::<usize>();
+        let free_space = TOTAL
+            .checked_sub(used_space)
+            .ok_or_else(|| Error::solve(Self::DAY, "the files don't fit on the disk"))?;
+        let minimum = REQUIRED.saturating_sub(free_space);
+
+        let dir_sizes = dir_sizes(entries);
+
+        // find the smallest folder which fulfills the requirements
+        let result = dir_sizes
+            .iter()
+            .filter(|s| {
+                if **s >= minimum {
+                    return true;
+                }
+                false
+            })
+            .min()
+            .ok_or_else(|| Error::solve(Self::DAY, "no directory frees up enough space"))?;
+
+        Ok((*result).into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day07::part1(&Day07::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day07::part2(&Day07::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/2022/day07/example.txt");
+
+    #[test]
+    fn malformed() {
+        let input = INPUT.replace("$ cd e", "$ cdd e");
+        let err = puzzle_1(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 7, line 13, column 1: expected a cd or ls command, found \"$\""
+        );
+
+        let input = INPUT.replace("584 i", "58k i");
+        let err = puzzle_2(&input).unwrap_err();
+        assert_eq!(err.location(), Some((15, 1)));
+    }
+}
@@ -0,0 +1,96 @@
+use common::{Answer, Result, Solution};
+use grid::Grid;
+
+fn parse_tree_grid(input: &str) -> Result<Grid<u8>> {
+    Grid::parse(Day08::DAY, input, "a tree height", |c| {
+        c.to_digit(10).map(|d| d as u8)
+    })
+}
+
+// number of trees seen from a tree of `height` looking along `trees`
+fn viewing_distance<'a>(trees: impl Iterator<Item = &'a u8>, height: u8) -> usize {
+    let mut distance = 0;
+    for tree in trees {
+        distance += 1;
+        if *tree >= height {
+            break;
+        }
+    }
+
+    distance
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Grid<u8>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_tree_grid(input)
+    }
+
+    fn part1(grid: &Self::Input, _: &()) -> Result<Answer> {
+        let visibility = grid
+            .iter()
+            .filter(|&((x, y), &tree)| {
+                let row = grid.row(y);
+
+                let west = row[..x].iter().all(|&t| t < tree);
+                let east = row[x + 1..].iter().all(|&t| t < tree);
+                let north = grid.column(x).take(y).all(|&t| t < tree);
+                let south = grid.column(x).skip(y + 1).all(|&t| t < tree);
+
+                north || east || south || west
+            })
+            .count();
+
+        Ok(visibility.into())
+    }
+
+    fn part2(grid: &Self::Input, _: &()) -> Result<Answer> {
+        let max = grid
+            .iter()
+            .map(|((x, y), &tree)| {
+                let row = grid.row(y);
+
+                let west = viewing_distance(row[..x].iter().rev(), tree);
+                let east = viewing_distance(row[x + 1..].iter(), tree);
+                let north = viewing_distance(grid.column(x).take(y).rev(), tree);
+                let south = viewing_distance(grid.column(x).skip(y + 1), tree);
+
+                north * east * south * west
+            })
+            .max()
+            .unwrap_or(0);
+
+        Ok(max.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day08::part1(&Day08::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day08::part2(&Day08::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1("303\n2552\n653").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 8, line 2, column 4: expected 3 columns, found \"2\""
+        );
+
+        let err = puzzle_2("303\n2x5\n653").unwrap_err();
+        assert_eq!(err.location(), Some((2, 2)));
+    }
+}
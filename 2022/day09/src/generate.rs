@@ -0,0 +1,14 @@
+use proptest::prelude::*;
+
+/// Motions of the head like `R 4`, short ones make the rope turn around a lot.
+pub fn moves() -> impl Strategy<Value = String> {
+    let motion = (prop::sample::select(&["R", "L", "U", "D"][..]), 1..12usize);
+
+    prop::collection::vec(motion, 1..80).prop_map(|motions| {
+        motions
+            .iter()
+            .map(|(dir, steps)| format!("{} {}", dir, steps))
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
@@ -0,0 +1,208 @@
+use std::{collections::HashSet, fmt::Display};
+
+use common::{observe, Answer, Error, Observer, Result, Solution};
+use geometry::{Dir4, Point2};
+use grid::SparseGrid;
+
+#[cfg(test)]
+mod generate;
+
+// the tail stays if it still touches the head, otherwise it steps towards it
+fn follow(tail: Point2, head: Point2) -> Point2 {
+    if tail.chebyshev(head) <= 1 {
+        return tail;
+    }
+
+    tail + (head - tail).signum()
+}
+
+fn parse_moves(input: &str) -> Result<Vec<Dir4>> {
+    let mut moves = vec![];
+
+    for line in input.lines() {
+        let (m, amount) = line
+            .split_once(' ')
+            .ok_or_else(|| Error::parse(Day09::DAY, input, &line[line.len()..], "' '"))?;
+
+        let m = match m {
+            "R" => Dir4::East,
+            "L" => Dir4::West,
+            "U" => Dir4::North,
+            "D" => Dir4::South,
+            _ => return Err(Error::parse(Day09::DAY, input, m, "R, L, U or D")),
+        };
+
+        let amount = amount
+            .parse::<usize>()
+            .map_err(|_| Error::parse(Day09::DAY, input, amount, "a number of steps"))?;
+
+        for _ in 0..amount {
+            moves.push(m);
+        }
+    }
+
+    Ok(moves)
+}
+
+const KNOTS: usize = 10;
+
+/// The knots of the rope, head first, and the positions its tail has visited.
+pub struct Rope {
+    pub knots: Vec<Point2>,
+    pub visited: HashSet<Point2>,
+}
+
+/// Draws the head as 'H', the other knots by their index, and the visited positions as '#'.
+impl Display for Rope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut cells = self
+            .visited
+            .iter()
+            .map(|&p| (p, '#'))
+            .collect::<SparseGrid<_>>();
+        cells.insert(Point2::ORIGIN, 's');
+
+        // knots in front cover the ones behind them
+        for (i, &knot) in self.knots.iter().enumerate().rev() {
+            let c = match i {
+                0 => 'H',
+                i => char::from_digit(i as u32 % 36, 36).unwrap(),
+            };
+            cells.insert(knot, c);
+        }
+
+        write!(f, "{}", cells)
+    }
+}
+
+/// Moves the head of a rope of `knots` and returns the number of positions the
+/// tail visited, the observer sees the rope after every step of the head.
+pub fn simulate(moves: &[Dir4], knots: usize, observer: &mut impl Observer<Rope>) -> Result<usize> {
+    let mut rope = Rope {
+        knots: vec![Point2::ORIGIN; knots],
+        visited: HashSet::from([Point2::ORIGIN]),
+    };
+
+    for (step, m) in moves.iter().enumerate() {
+        rope.knots[0] += m.unit();
+        for i in 1..knots {
+            rope.knots[i] = follow(rope.knots[i], rope.knots[i - 1]);
+        }
+        rope.visited.insert(rope.knots[knots - 1]);
+
+        observe(Day09::DAY, observer, step + 1, &rope)?;
+    }
+
+    Ok(rope.visited.len())
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Dir4>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_moves(input)
+    }
+
+    fn part1(moves: &Self::Input, _: &()) -> Result<Answer> {
+        Ok(simulate(moves, 2, &mut ())?.into())
+    }
+
+    fn part2(moves: &Self::Input, _: &()) -> Result<Answer> {
+        Ok(simulate(moves, KNOTS, &mut ())?.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day09::part1(&Day09::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day09::part2(&Day09::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // the rules of the puzzle as they are written, one motion after the other
+    fn brute_force(input: &str, knots: usize) -> usize {
+        let mut rope = vec![(0i32, 0i32); knots];
+        let mut visited = BTreeSet::from([(0, 0)]);
+
+        for line in input.lines() {
+            let (dir, steps) = line.split_once(' ').unwrap();
+            let (dx, dy) = match dir {
+                "R" => (1, 0),
+                "L" => (-1, 0),
+                "U" => (0, -1),
+                _ => (0, 1),
+            };
+
+            for _ in 0..steps.parse::<usize>().unwrap() {
+                rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+                for i in 1..knots {
+                    let ((hx, hy), (tx, ty)) = (rope[i - 1], rope[i]);
+                    // a knot only moves if it doesn't touch the one in front anymore
+                    if (hx - tx).abs() > 1 || (hy - ty).abs() > 1 {
+                        rope[i] = (tx + (hx - tx).signum(), ty + (hy - ty).signum());
+                    }
+                }
+                visited.insert(rope[knots - 1]);
+            }
+        }
+
+        visited.len()
+    }
+
+    proptest! {
+        #[test]
+        fn brute_forced(input in generate::moves()) {
+            prop_assert_eq!(puzzle_1(&input), Ok(brute_force(&input, 2).into()));
+            prop_assert_eq!(puzzle_2(&input), Ok(brute_force(&input, KNOTS).into()));
+        }
+    }
+
+    #[test]
+    fn observed() {
+        let moves = Day09::load("R 4\nU 4\nL 3").unwrap();
+
+        // the tail always touches the knot in front of it
+        let mut touching = |_, rope: &Rope| {
+            assert!(rope.knots.windows(2).all(|k| k[0].chebyshev(k[1]) <= 1));
+            std::ops::ControlFlow::Continue(())
+        };
+        assert_eq!(simulate(&moves, KNOTS, &mut touching), Ok(1));
+
+        let mut last = String::new();
+        let mut drawn = |_, rope: &Rope| {
+            last = rope.to_string();
+            std::ops::ControlFlow::Continue(())
+        };
+        simulate(&moves, 3, &mut drawn).unwrap();
+        assert_eq!(last, ".H1..\n...2.\n....#\n...#.\ns##..");
+
+        let err = simulate(&moves, 2, &mut common::StepLimit(5)).unwrap_err();
+        assert_eq!(err.to_string(), "day 9: stopped after step 5");
+    }
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1("R 4\nX 4").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 9, line 2, column 1: expected R, L, U or D, found \"X\""
+        );
+
+        let err = puzzle_2("R 4\nU").unwrap_err();
+        assert_eq!(err.location(), Some((2, 2)));
+    }
+}
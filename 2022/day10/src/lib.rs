@@ -0,0 +1,123 @@
+use common::{Answer, Error, Result, Solution};
+
+pub enum Instruction {
+    Noop,
+    AddX(i32),
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>> {
+    let ins = input
+        .lines()
+        .map(|l| {
+            if l == "noop" {
+                return Ok(Instruction::Noop);
+            }
+
+            let amount = l
+                .strip_prefix("addx ")
+                .ok_or_else(|| Error::parse(Day10::DAY, input, l, "noop or addx"))?;
+            let amount = amount
+                .parse::<i32>()
+                .map_err(|_| Error::parse(Day10::DAY, input, amount, "a number"))?;
+            Ok(Instruction::AddX(amount))
+        })
+        .collect::<Result<Vec<Instruction>>>()?;
+
+    Ok(ins)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input, _: &()) -> Result<Answer> {
+        let mut cycles = Vec::from([1]);
+
+        for ins in instructions {
+            let last = *cycles.last().unwrap();
+            match ins {
+                Instruction::Noop => cycles.push(last),
+                Instruction::AddX(value) => {
+                    cycles.push(last);
+                    cycles.push(last + value);
+                }
+            }
+        }
+
+        // filter the required cycles and calculate their strength
+        let filtered_cycles = cycles
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, v)| {
+                let cycle_index = idx + 1;
+                if [20, 60, 100, 140, 180, 220].contains(&cycle_index) {
+                    return Some(*v * cycle_index as i32);
+                }
+                None
+            })
+            .collect::<Vec<i32>>();
+
+        let sum = filtered_cycles.iter().sum::<i32>();
+
+        Ok(sum.into())
+    }
+
+    fn part2(instructions: &Self::Input, _: &()) -> Result<Answer> {
+        let mut x_values = Vec::from([1]);
+
+        for ins in instructions {
+            let last = *x_values.last().unwrap();
+            match ins {
+                Instruction::Noop => x_values.push(last),
+                Instruction::AddX(value) => {
+                    x_values.push(last);
+                    x_values.push(last + value);
+                }
+            }
+        }
+
+        let mut pixels = Vec::<bool>::new();
+        for (i, v) in x_values.iter().take(240).enumerate() {
+            let x = i % 40;
+
+            pixels.push((v - x as i32).abs() <= 1);
+        }
+
+        let rows = pixels.chunks(40).map(|s| s.to_vec()).collect();
+
+        Ok(Answer::Pixels(rows))
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day10::part1(&Day10::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day10::part2(&Day10::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1("noop\naddx 3\naddx -x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 10, line 3, column 6: expected a number, found \"-x\""
+        );
+
+        let err = puzzle_2("noop\nmulx 3").unwrap_err();
+        assert_eq!(err.location(), Some((2, 1)));
+    }
+}
@@ -0,0 +1,280 @@
+use common::{Answer, Error, Result, Solution};
+use serde::Deserialize;
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    // tuple (a, b, c) representing ax^2 + bx + c
+    operation: (u64, u64, u64),
+    test_division: u64,
+    // tuple for throw targets (true, false)
+    targets: (usize, usize),
+    inspections: u64,
+}
+
+// strips the prefix from the next line of a monkey description
+fn field<'a>(
+    input: &str,
+    block: &'a str,
+    lines: &mut std::str::Lines<'a>,
+    prefix: &str,
+) -> Result<&'a str> {
+    let expected = || format!("\"{}\"", prefix.trim_start());
+
+    let line = lines
+        .next()
+        .ok_or_else(|| Error::parse(Day11::DAY, input, &block[block.len()..], expected()))?;
+
+    line.strip_prefix(prefix)
+        .ok_or_else(|| Error::parse(Day11::DAY, input, line.trim_start(), expected()))
+}
+
+fn number<T: std::str::FromStr>(input: &str, n: &str) -> Result<T> {
+    n.parse::<T>()
+        .map_err(|_| Error::parse(Day11::DAY, input, n, "a number"))
+}
+
+impl Monkey {
+    pub fn parse(input: &str, block: &str) -> Result<Self> {
+        let mut lines = block.lines();
+
+        field(input, block, &mut lines, "Monkey ")?;
+
+        // starting items
+        let starting_items = field(input, block, &mut lines, "  Starting items: ")?
+            .split(", ")
+            .map(|n| number(input, n))
+            .collect::<Result<Vec<u64>>>()?;
+
+        // operation
+        let mut operation = (0, 0, 0);
+        let line = field(input, block, &mut lines, "  Operation: new = old ")?;
+        if line == "* old" {
+            operation.0 = 1;
+        } else if let Some(b) = line.strip_prefix("* ") {
+            operation.1 = number(input, b)?;
+        } else if let Some(c) = line.strip_prefix("+ ") {
+            operation.2 = number(input, c)?;
+            operation.1 = 1;
+        } else {
+            return Err(Error::parse(Day11::DAY, input, line, "a monkey operation"));
+        }
+
+        // test division
+        let line = field(input, block, &mut lines, "  Test: divisible by ")?;
+        let test_division = number(input, line)?;
+        if test_division == 0 {
+            return Err(Error::parse(Day11::DAY, input, line, "a non-zero divisor"));
+        }
+
+        //targets
+        let line = field(input, block, &mut lines, "    If true: throw to monkey ")?;
+        let t = number(input, line)?;
+
+        let line = field(input, block, &mut lines, "    If false: throw to monkey ")?;
+        let f = number(input, line)?;
+
+        Ok(Monkey {
+            items: starting_items,
+            operation,
+            test_division,
+            targets: (t, f),
+            inspections: 0,
+        })
+    }
+}
+
+/// Rounds of part 1 and part 2, fewer rounds are easier to follow.
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub rounds: (u32, u32),
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            rounds: (20, 10000),
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let monkeys = input
+            .split("\n\n")
+            .map(|block| Monkey::parse(input, block))
+            .collect::<Result<Vec<Monkey>>>()?;
+
+        for (i, m) in monkeys.iter().enumerate() {
+            if m.targets.0 >= monkeys.len()
+                || m.targets.1 >= monkeys.len()
+                || m.targets.0 == i
+                || m.targets.1 == i
+            {
+                let message = format!("monkey {} throws to an unknown monkey", i);
+                return Err(Error::solve(Self::DAY, message));
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        let mut monkeys = monkeys.clone();
+
+        for _round in 0..params.rounds.0 {
+            for m in 0..monkeys.len() {
+                let monkey = monkeys.get_mut(m).unwrap();
+
+                // list for modified items and their targets
+                let mut items = Vec::new();
+
+                let item_count = monkey.items.len();
+                for _ in 0..item_count {
+                    // take the item
+                    let mut item = monkey.items.remove(0);
+
+                    // do the operation
+                    item = monkey.operation.0 * item * item
+                        + monkey.operation.1 * item
+                        + monkey.operation.2;
+
+                    // monkey gets bored
+                    item /= 3;
+
+                    // test
+                    let target = if item % monkey.test_division == 0 {
+                        monkey.targets.0
+                    } else {
+                        monkey.targets.1
+                    };
+
+                    // move the item
+                    items.push((target, item));
+
+                    // an item was inspected
+                    monkey.inspections += 1;
+                }
+
+                // move the items
+                for (t, i) in items {
+                    let monkey = monkeys.get_mut(t).unwrap();
+                    monkey.items.push(i);
+                }
+            }
+        }
+
+        let mut inspections = monkeys.iter().map(|m| m.inspections).collect::<Vec<u64>>();
+        inspections.sort_by(|a, b| b.cmp(a));
+
+        let business = inspections.iter().take(2).product::<u64>();
+
+        Ok(business.into())
+    }
+
+    fn part2(monkeys: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        let mut monkeys = monkeys.clone();
+
+        let modulo = monkeys.iter().map(|m| m.test_division).product::<u64>();
+
+        for _round in 0..params.rounds.1 {
+            for m in 0..monkeys.len() {
+                let monkey = monkeys.get_mut(m).unwrap();
+
+                // list for modified items and their targets
+                let mut items = Vec::new();
+
+                let item_count = monkey.items.len();
+                for _ in 0..item_count {
+                    // take the item
+                    let mut item = monkey.items.remove(0);
+
+                    // do the operation
+                    item = monkey.operation.0 * item * item
+                        + monkey.operation.1 * item
+                        + monkey.operation.2;
+
+                    // keep the numbers managable
+                    item %= modulo;
+
+                    // test
+                    let target = if item % monkey.test_division == 0 {
+                        monkey.targets.0
+                    } else {
+                        monkey.targets.1
+                    };
+
+                    // move the item
+                    items.push((target, item));
+
+                    // an item was inspected
+                    monkey.inspections += 1;
+                }
+
+                // move the items
+                for (t, i) in items {
+                    let monkey = monkeys.get_mut(t).unwrap();
+                    monkey.items.push(i);
+                }
+            }
+        }
+
+        let mut inspections = monkeys.iter().map(|m| m.inspections).collect::<Vec<u64>>();
+        inspections.sort_by(|a, b| b.cmp(a));
+
+        let business = inspections.iter().take(2).product::<u64>();
+
+        Ok(business.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day11::part1(&Day11::load(input)?, &Params::default())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day11::part2(&Day11::load(input)?, &Params::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/2022/day11/example.txt");
+
+    #[test]
+    fn truncated() {
+        let input = INPUT.trim_end();
+        let err = puzzle_1(&input[..input.len() - 32]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 11, line 26, column 31: expected \"If false: throw to monkey \", found end of input"
+        );
+    }
+
+    #[test]
+    fn rounds() {
+        let monkeys = Day11::load(INPUT).unwrap();
+        let params = Params { rounds: (20, 20) };
+        // the inspections after round 20 of part 2 are 99, 97, 8 and 103
+        assert_eq!(
+            Day11::part2(&monkeys, &params).unwrap(),
+            Answer::from(103 * 99)
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let input = INPUT.replace("new = old * 19", "new = old / 19");
+        let err = puzzle_2(&input).unwrap_err();
+        assert_eq!(err.location(), Some((3, 24)));
+    }
+}
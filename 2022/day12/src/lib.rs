@@ -0,0 +1,152 @@
+use common::{Answer, Error, Result, Solution};
+use grid::Grid;
+use petgraph::{
+    algo::dijkstra,
+    graph::{Graph, NodeIndex},
+    Directed,
+};
+
+enum Elevation {
+    Start,
+    Goal,
+    Node(i32),
+}
+
+fn parse_graph(input: &str) -> Result<(NodeIndex, NodeIndex, Graph<i32, i32>)> {
+    let (mut has_start, mut has_goal) = (false, false);
+    let elevations = Grid::parse(Day12::DAY, input, "an elevation", |c| match c {
+        'S' if !has_start => {
+            has_start = true;
+            Some(Elevation::Start)
+        }
+        'E' if !has_goal => {
+            has_goal = true;
+            Some(Elevation::Goal)
+        }
+        'a'..='z' => Some(Elevation::Node((c as i32) - 96)),
+        _ => None,
+    })?;
+
+    let end = &input[input.len()..];
+    if !has_start {
+        return Err(Error::parse(Day12::DAY, input, end, "a start position 'S'"));
+    } else if !has_goal {
+        return Err(Error::parse(Day12::DAY, input, end, "a goal position 'E'"));
+    }
+
+    let mut start = NodeIndex::new(0);
+    let mut goal = NodeIndex::new(0);
+    let mut graph = Graph::<i32, i32, Directed>::new();
+
+    // add the nodes, row by row so a node index matches its position in the grid
+    for (_, el) in elevations.iter() {
+        match el {
+            Elevation::Start => {
+                start = graph.add_node(1);
+            }
+            Elevation::Goal => {
+                goal = graph.add_node(26);
+            }
+            Elevation::Node(v) => {
+                graph.add_node(*v);
+            }
+        }
+    }
+
+    // add the edges
+    let width = elevations.width();
+    for (x, y) in elevations.positions() {
+        let index = NodeIndex::new(x + y * width);
+        let value = graph[index];
+
+        for (nx, ny) in elevations.neighbours4((x, y)) {
+            let n_index = NodeIndex::new(nx + ny * width);
+
+            if graph[n_index] - value <= 1 {
+                graph.add_edge(index, n_index, 1);
+            }
+        }
+    }
+
+    Ok((start, goal, graph))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = (NodeIndex, NodeIndex, Graph<i32, i32>);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_graph(input)
+    }
+
+    fn part1(heightmap: &Self::Input, _: &()) -> Result<Answer> {
+        let &(start, goal, ref graph) = heightmap;
+
+        let costs = dijkstra(graph, start, Some(goal), |_| 1);
+
+        let length = costs
+            .get(&goal)
+            .ok_or_else(|| Error::solve(Self::DAY, "the goal can't be reached"))?;
+
+        Ok((*length).into())
+    }
+
+    fn part2(heightmap: &Self::Input, _: &()) -> Result<Answer> {
+        let &(_, goal, ref graph) = heightmap;
+        let mut graph = graph.clone();
+
+        // reverse the edges and calculate all costs starting from the goal
+        graph.reverse();
+        let costs = dijkstra(&graph, goal, None, |_| 1);
+
+        // filter out the costs that don't end at elevation 1
+        let filtered_costs = costs
+            .iter()
+            .filter_map(|(idx, cost)| {
+                let elevation = graph[*idx];
+                if elevation == 1 {
+                    return Some(*cost);
+                }
+                None
+            })
+            .collect::<Vec<i32>>();
+
+        let length = filtered_costs
+            .iter()
+            .min()
+            .ok_or_else(|| Error::solve(Self::DAY, "the goal can't be reached"))?;
+
+        Ok((*length).into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day12::part1(&Day12::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day12::part2(&Day12::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/2022/day12/example.txt");
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1(&INPUT.replace('E', "#")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 12, line 3, column 6: expected an elevation, found \"#xk\""
+        );
+
+        let err = puzzle_2(&INPUT.replace('E', "z")).unwrap_err();
+        assert_eq!(err.location(), Some((5, 9)));
+    }
+}
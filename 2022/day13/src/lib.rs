@@ -0,0 +1,174 @@
+use std::cmp::Ordering;
+
+use common::{Answer, Error, Result, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::newline,
+    combinator::{all_consuming, cut},
+    multi::{separated_list0, separated_list1},
+    sequence::{preceded, separated_pair, terminated},
+    IResult, Parser,
+};
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    Integer(u32),
+    List(Vec<Value>),
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Value {}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Value::Integer(i1), Value::Integer(i2)) => i1.cmp(i2),
+            (Value::Integer(i), Value::List(_)) => {
+                let tmp_list = vec![Value::Integer(*i)];
+                let entry = Value::List(tmp_list);
+                entry.cmp(other)
+            }
+            (Value::List(_), Value::Integer(_)) => {
+                let res = other.cmp(self);
+                res.reverse()
+            }
+            (Value::List(l1), Value::List(l2)) => {
+                let length = l1.len().min(l2.len());
+
+                for i in 0..length {
+                    let e1 = &l1[i];
+
+                    let e2 = &l2[i];
+
+                    let res = e1.cmp(e2);
+
+                    if res != Ordering::Equal {
+                        return res;
+                    }
+                }
+
+                l1.len().cmp(&l2.len())
+            }
+        }
+    }
+}
+
+fn packet(input: &str) -> IResult<&str, Value> {
+    alt((
+        // a bracket always starts a list, so fail early on the exact position
+        preceded(
+            tag("["),
+            cut(terminated(separated_list0(tag(","), packet), tag("]"))),
+        )
+        .map(Value::List),
+        nom::character::complete::u32.map(Value::Integer),
+    ))(input)
+}
+
+fn pairs(input: &str) -> IResult<&str, Vec<(Value, Value)>> {
+    all_consuming(separated_list1(
+        tag("\n\n"),
+        cut(separated_pair(packet, newline, packet)),
+    ))(input)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<(Value, Value)>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (_input, packets) =
+            pairs(input).map_err(|e| Error::nom(Self::DAY, input, e, "a packet"))?;
+
+        Ok(packets)
+    }
+
+    fn part1(packets: &Self::Input, _: &()) -> Result<Answer> {
+        let indicies = packets
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, (p1, p2))| {
+                if p1 < p2 {
+                    return Some(idx + 1);
+                }
+                None
+            })
+            .collect::<Vec<usize>>();
+
+        let sum = indicies.iter().sum::<usize>();
+
+        Ok(sum.into())
+    }
+
+    fn part2(packets: &Self::Input, _: &()) -> Result<Answer> {
+        // flatten the pairs
+        let mut packets = packets
+            .iter()
+            .flat_map(|(p1, p2)| vec![p1.clone(), p2.clone()])
+            .collect::<Vec<Value>>();
+
+        // add the divider packtes
+        let d1 = Value::List(vec![Value::List(vec![Value::Integer(2)])]);
+        let d2 = Value::List(vec![Value::List(vec![Value::Integer(6)])]);
+        packets.push(d1.clone());
+        packets.push(d2.clone());
+
+        packets.sort();
+
+        let i1 = packets.binary_search(&d1).unwrap();
+        let i2 = packets.binary_search(&d2).unwrap();
+
+        Ok(((i1 + 1) * (i2 + 1)).into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day13::part1(&Day13::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day13::part2(&Day13::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/2022/day13/example.txt");
+
+    #[test]
+    fn line_endings() {
+        let input = INPUT.replace('\n', "\r\n") + "\r\n";
+        let result = puzzle_1(&input).unwrap();
+        assert_eq!(result, Answer::Integer(13));
+    }
+
+    #[test]
+    fn malformed() {
+        let input = INPUT.replace("[1,[2,[3,[4,[5,6,0]]]],8,9]", "[1,[2,[3,[4,[5,6,0]]]],8,9");
+        let err = puzzle_1(&input).unwrap_err();
+        assert_eq!(err.location(), Some((23, 27)));
+
+        let err = puzzle_2("[1,1,3,1,1]\n[1,1,a,1,1]").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 13, line 2, column 5: expected a packet, found \",a,1,1]\""
+        );
+    }
+}
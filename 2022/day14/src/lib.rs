@@ -0,0 +1,236 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use common::{observe, Answer, Error, Observer, Result, Solution};
+use geometry::{Dir8, Point2};
+use grid::SparseGrid;
+
+#[derive(Clone)]
+enum Tile {
+    Rock,
+    Sand,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Rock => write!(f, "#"),
+            Tile::Sand => write!(f, "o"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Cave {
+    tiles: SparseGrid<Tile>,
+    lowest: i32,
+}
+
+const SOURCE: Point2 = Point2::new(500, 0);
+
+/// Draws the rock and sand with the source of the sand as '+'.
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut cells = self
+            .tiles
+            .iter()
+            .map(|(&p, t)| (p, t.to_string()))
+            .collect::<SparseGrid<_>>();
+        if !cells.contains(SOURCE) {
+            cells.insert(SOURCE, "+".to_string());
+        }
+
+        write!(f, "{}", cells)
+    }
+}
+
+// sand falls straight down if possible, otherwise diagonally left and then right
+const FALLING: [Dir8; 3] = [Dir8::South, Dir8::SouthWest, Dir8::SouthEast];
+
+fn parse_coord(input: &str, n: &str) -> Result<i32> {
+    n.parse::<i32>()
+        .map_err(|_| Error::parse(Day14::DAY, input, n, "a coordinate"))
+}
+
+fn parse_ranges(input: &str) -> Result<Vec<(RangeInclusive<i32>, RangeInclusive<i32>)>> {
+    let mut all = Vec::new();
+
+    for line in input.lines() {
+        // parse pairs of coordinats
+        let mut coords: Vec<Point2> = Vec::new();
+        for pair in line.split(" -> ") {
+            let (x, y) = pair
+                .split_once(',')
+                .ok_or_else(|| Error::parse(Day14::DAY, input, pair, "a pair of coordinates"))?;
+            let p = Point2::new(parse_coord(input, x)?, parse_coord(input, y)?);
+
+            // rock paths only run straight
+            if let Some(prev) = coords.last() {
+                if prev.x != p.x && prev.y != p.y {
+                    let expected = "a horizontal or vertical line";
+                    return Err(Error::parse(Day14::DAY, input, pair, expected));
+                }
+            }
+            coords.push(p);
+        }
+
+        // convert the coordinates into ranges for x and y
+        let ranges = coords
+            .windows(2)
+            .map(|pair| {
+                let mut x = [pair[0].x, pair[1].x];
+                x.sort();
+
+                let mut y = [pair[0].y, pair[1].y];
+                y.sort();
+
+                (x[0]..=x[1], y[0]..=y[1])
+            })
+            .collect::<Vec<(RangeInclusive<i32>, RangeInclusive<i32>)>>();
+
+        all.extend(ranges);
+    }
+
+    Ok(all)
+}
+
+fn parse_cave(input: &str) -> Result<Cave> {
+    let mut tiles = SparseGrid::new();
+    let mut lowest = 0;
+
+    let ranges = parse_ranges(input)?;
+
+    for range in ranges.iter() {
+        for x in range.0.clone() {
+            for y in range.1.clone() {
+                tiles.insert(Point2::new(x, y), Tile::Rock);
+            }
+        }
+
+        if *range.1.end() > lowest {
+            lowest = *range.1.end();
+        }
+    }
+
+    Ok(Cave { tiles, lowest })
+}
+
+/// Pours sand until it falls into the void, or with a `floor` two below the lowest
+/// rock until the source is blocked, and returns the units of sand at rest.
+///
+/// The observer sees the cave whenever another unit comes to rest.
+pub fn pour(cave: &Cave, floor: bool, observer: &mut impl Observer<Cave>) -> Result<usize> {
+    let mut cave = cave.clone();
+    let floor = floor.then_some(cave.lowest + 2);
+
+    let mut units = 0;
+    loop {
+        let mut pos = SOURCE;
+
+        // falling
+        loop {
+            let next = FALLING
+                .iter()
+                .map(|d| pos + d.unit())
+                .find(|&p| !cave.tiles.contains(p) && Some(p.y) != floor);
+
+            match next {
+                // falling in the void
+                Some(next) if floor.is_none() && next.y > cave.lowest => return Ok(units),
+                Some(next) => pos = next,
+                None => break,
+            }
+        }
+
+        // resting position
+        cave.tiles.insert(pos, Tile::Sand);
+        units += 1;
+        observe(Day14::DAY, observer, units, &cave)?;
+
+        if pos == SOURCE {
+            return Ok(units);
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Cave;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_cave(input)
+    }
+
+    fn part1(cave: &Self::Input, _: &()) -> Result<Answer> {
+        Ok(pour(cave, false, &mut ())?.into())
+    }
+
+    fn part2(cave: &Self::Input, _: &()) -> Result<Answer> {
+        Ok(pour(cave, true, &mut ())?.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day14::part1(&Day14::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day14::part2(&Day14::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn observed() {
+        let cave =
+            Day14::load("498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9").unwrap();
+
+        // sand piles up below the source and never reaches the floor
+        let mut above_floor = |_, cave: &Cave| {
+            assert!(cave.tiles.positions().all(|p| p.y < cave.lowest + 2));
+            std::ops::ControlFlow::Continue(())
+        };
+        assert_eq!(pour(&cave, true, &mut above_floor), Ok(93));
+
+        let mut last = String::new();
+        let mut drawn = |_, cave: &Cave| {
+            last = cave.to_string();
+            std::ops::ControlFlow::Continue(())
+        };
+        pour(&cave, false, &mut drawn).unwrap();
+        assert_eq!(
+            last,
+            "\
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########."
+        );
+
+        let err = pour(&cave, false, &mut common::StepLimit(5)).unwrap_err();
+        assert_eq!(err.to_string(), "day 14: stopped after step 5");
+    }
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1("498,4 -> 498,6 -> 496,7").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 14, line 1, column 19: expected a horizontal or vertical line, found \"496,7\""
+        );
+
+        let err = puzzle_2("498,4 -> 498,6\n503,4 -> 502;4").unwrap_err();
+        assert_eq!(err.location(), Some((2, 10)));
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::HashSet;
+use std::ops::Range;
+
+use common::{Answer, Error, Result, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    combinator::all_consuming,
+    sequence::{preceded, separated_pair},
+    IResult, Parser,
+};
+use serde::Deserialize;
+
+#[derive(Debug)]
+pub struct Sensor {
+    pos: (i32, i32),
+    beacon: (i32, i32),
+    range: u32,
+}
+
+impl Sensor {
+    pub fn new(pos: (i32, i32), beacon: (i32, i32)) -> Self {
+        let range = pos.0.abs_diff(beacon.0) + pos.1.abs_diff(beacon.1);
+
+        Sensor { pos, beacon, range }
+    }
+
+    pub fn cover_at_row(&self, row: i32) -> Range<i32> {
+        let vertical_distance = self.pos.1.abs_diff(row);
+        if vertical_distance > self.range {
+            return 0..0;
+        }
+
+        let diff = (self.range as i32) - (vertical_distance as i32);
+
+        (self.pos.0 - diff)..(self.pos.0 + diff + 1)
+    }
+}
+
+fn position(input: &str) -> IResult<&str, (i32, i32)> {
+    separated_pair(
+        preceded(tag("x="), complete::i32),
+        tag(", "),
+        preceded(tag("y="), complete::i32),
+    )(input)
+}
+
+fn sensor(input: &str) -> IResult<&str, Sensor> {
+    separated_pair(
+        preceded(tag("Sensor at "), position),
+        tag(": "),
+        preceded(tag("closest beacon is at "), position),
+    )
+    .map(|pair| Sensor::new(pair.0, pair.1))
+    .parse(input)
+}
+
+fn parse_sensors(input: &str) -> Result<Vec<Sensor>> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, sensor) = all_consuming(sensor)(line)
+                .map_err(|e| Error::nom(Day15::DAY, input, e, "a sensor report"))?;
+            Ok(sensor)
+        })
+        .collect()
+}
+
+/// Row and search area of the real puzzle input,
+/// the example uses a row of 10 and bounds of (0, 20).
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub row: i32,
+    pub bounds: (i32, i32),
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            row: 2000000,
+            bounds: (0, 4000000),
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Vec<Sensor>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_sensors(input)
+    }
+
+    fn part1(sensors: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        let y = params.row;
+
+        let mut positions = HashSet::new();
+
+        for s in sensors.iter() {
+            let range = s.cover_at_row(y);
+
+            for p in range {
+                positions.insert(p);
+            }
+        }
+
+        // remove the beacons directly on the row from the covered positions
+        for s in sensors.iter() {
+            if s.beacon.1 == y {
+                positions.remove(&s.beacon.0);
+            }
+        }
+
+        Ok(positions.len().into())
+    }
+
+    fn part2(sensors: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        let bounds = params.bounds;
+
+        let mut target = (-1, -1);
+
+        for row in bounds.0..bounds.1 {
+            let mut ranges = sensors
+                .iter()
+                .filter_map(|s| {
+                    let range = s.cover_at_row(row);
+                    if range.is_empty() {
+                        return None;
+                    }
+                    Some(range)
+                })
+                .collect::<Vec<Range<i32>>>();
+
+            ranges.sort_by_key(|a| a.start);
+
+            // build a continous range or find a hole
+            let Some(start) = ranges.first().cloned() else {
+                target = (bounds.0, row);
+                break;
+            };
+            let (complete, hole) = ranges.iter().fold((start, None), |acc, r| {
+                if acc.1.is_some() {
+                    return (0..0, acc.1);
+                }
+
+                if acc.0.end < r.start {
+                    return (0..0, Some(acc.0.end));
+                }
+
+                let start = acc.0.start;
+                let end = acc.0.end.max(r.end);
+
+                (start..end, None)
+            });
+
+            if let Some(x) = hole {
+                target = (x, row);
+                break;
+            } else if complete.start > bounds.0 {
+                target = (0, row);
+                break;
+            } else if complete.end <= bounds.1 {
+                target = (bounds.1, row);
+                break;
+            }
+        }
+        // println!("target position: ({}, {})", target.0, target.1);
+        if target == (-1, -1) {
+            return Err(Error::solve(Self::DAY, "every position is covered"));
+        }
+
+        let frequency = (target.0 as u64) * 4000000 + target.1 as u64;
+
+        Ok(frequency.into())
+    }
+}
+
+pub fn puzzle_1(input: &str, y: i32) -> Result<Answer> {
+    let params = Params {
+        row: y,
+        ..Default::default()
+    };
+    Day15::part1(&Day15::load(input)?, &params)
+}
+
+pub fn puzzle_2(input: &str, bounds: (i32, i32)) -> Result<Answer> {
+    let params = Params {
+        bounds,
+        ..Default::default()
+    };
+    Day15::part2(&Day15::load(input)?, &params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/2022/day15/example.txt");
+
+    #[test]
+    fn malformed() {
+        let input = INPUT.replace("x=12, y=14", "x=12 y=14");
+        let err = puzzle_1(&input, 10).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 15, line 4, column 15: expected a sensor report, found \" y=14:\""
+        );
+
+        let input = INPUT.trim_end();
+        let err = puzzle_2(&input[..input.len() - 6], (0, 20)).unwrap_err();
+        assert_eq!(err.location(), Some((14, 46)));
+    }
+}
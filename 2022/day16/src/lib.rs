@@ -0,0 +1,441 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use common::{Answer, Assumption, Error, Result, Solution};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1},
+    combinator::all_consuming,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+#[derive(Debug)]
+pub struct Valve {
+    flow: u32,
+    paths: HashMap<String, u32>,
+}
+
+type ValveEntry = (String, (u32, Vec<String>));
+
+fn valve(input: &str) -> IResult<&str, ((&str, u32), Vec<&str>)> {
+    separated_pair(
+        preceded(
+            tag("Valve "),
+            separated_pair(alpha1::<&str, _>, tag(" has flow rate="), complete::u32),
+        ),
+        tag("; "),
+        preceded(
+            alt((
+                tag("tunnels lead to valves "),
+                tag("tunnel leads to valve "),
+            )),
+            separated_list1(tag(", "), alpha1),
+        ),
+    )(input)
+}
+
+fn parse_valves(input: &str) -> Result<Vec<ValveEntry>> {
+    let valves = input
+        .lines()
+        .map(|line| {
+            all_consuming(valve)(line)
+                .map(|(_, v)| v)
+                .map_err(|e| Error::nom(Day16::DAY, input, e, "a valve description"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // every tunnel has to lead to a known valve
+    let ids = valves
+        .iter()
+        .map(|((id, _), _)| *id)
+        .collect::<HashSet<_>>();
+    for (_, tunnels) in valves.iter() {
+        if let Some(t) = tunnels.iter().find(|t| !ids.contains(*t)) {
+            return Err(Error::parse(Day16::DAY, input, t, "a known valve"));
+        }
+    }
+    if !ids.contains("AA") {
+        let end = &input[input.len()..];
+        return Err(Error::parse(Day16::DAY, input, end, "the start valve AA"));
+    }
+
+    Ok(valves
+        .into_iter()
+        .map(|((id, flow), tunnels)| {
+            let paths = tunnels.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+            (id.to_string(), (flow, paths))
+        })
+        .collect())
+}
+
+fn build_graph(input: &str) -> Result<HashMap<String, Valve>> {
+    let valves = parse_valves(input)?;
+
+    let flows = valves
+        .iter()
+        .map(|(k, (f, _))| (k.clone(), *f))
+        .collect::<HashMap<_, _>>();
+
+    let paths = valves
+        .into_iter()
+        .map(|(k, (_, p))| (k, p))
+        .collect::<HashMap<_, _>>();
+
+    let mut valves = HashMap::new();
+
+    for id in flows.keys() {
+        if id != "AA" && flows[id] == 0 {
+            continue;
+        }
+
+        // let mut dist = HashMap::from([("AA".to_string(), 0)]);
+        let mut dist = HashMap::new();
+        let mut visited = HashSet::from([id]);
+        let mut queue = VecDeque::from([(0, id)]);
+
+        while !queue.is_empty() {
+            let (distance, id) = queue.pop_front().unwrap();
+
+            for neighbor in paths[id].iter() {
+                if visited.contains(neighbor) {
+                    continue;
+                }
+                visited.insert(neighbor);
+                if flows[neighbor] > 0 {
+                    // insert dist
+                    dist.insert(neighbor.clone(), distance + 1);
+                }
+
+                queue.push_back((distance + 1, neighbor));
+            }
+        }
+        valves.insert(
+            id.clone(),
+            Valve {
+                flow: flows[id],
+                paths: dist,
+            },
+        );
+    }
+
+    Ok(valves)
+}
+
+/// Bits of the valves worth opening in the masks of the closed valves.
+type Bits<'a> = HashMap<&'a str, u64>;
+
+/// Pressure the searches already done can still release, by their valve, minutes left
+/// and closed valves.
+type Memo<'a> = HashMap<(&'a str, u32, u64), u32>;
+
+fn bits<'a>(useful_valves: &[&'a str]) -> Result<Bits<'a>> {
+    if useful_valves.len() > u64::BITS as usize {
+        let message = format!(
+            "{} valves have a flow rate, at most {} fit in a mask",
+            useful_valves.len(),
+            u64::BITS
+        );
+        return Err(Error::solve(Day16::DAY, message));
+    }
+
+    Ok(useful_valves
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| (v, 1 << i))
+        .collect())
+}
+
+// the most pressure that can still be released, the value so far doesn't change it
+fn search<'a>(
+    valves: &'a HashMap<String, Valve>,
+    bits: &Bits<'a>,
+    current_valve: &'a str,
+    minutes: u32,
+    closed_valves: u64,
+    memo: &mut Memo<'a>,
+) -> u32 {
+    let key = (current_valve, minutes, closed_valves);
+    if let Some(&result) = memo.get(&key) {
+        return result;
+    }
+
+    let paths = &valves[current_valve].paths;
+
+    let result = paths
+        .iter()
+        .filter_map(|(p, d)| {
+            let bit = bits[p.as_str()];
+            // skip closed valves and not reachable
+            if closed_valves & bit != 0 || *d + 1 > minutes {
+                return None;
+            }
+            let new_minutes = minutes - d - 1;
+            // get the flow rate for the closed one
+            let flow = valves[p].flow;
+
+            // recursevily calculate the value for the newly closed valve
+            let rest = search(valves, bits, p, new_minutes, closed_valves | bit, memo);
+            Some(new_minutes * flow + rest)
+        })
+        .max()
+        .unwrap_or(0);
+
+    memo.insert(key, result);
+    result
+}
+
+// #[cached(
+//     key = "String",
+//     convert = r#"{
+//         let start = "".to_string();
+//         format!("{}-{}-{}-{}-{}-{}", current_value, me.0, me.1, elephant.0, elephant.1, closed_valves.iter().fold(start, |acc, &curr| {
+//                 format!("{}{},", acc, curr)
+//             })
+//         )
+//     }"#
+// )]
+// fn search_elephant(
+//     valves: &HashMap<String, Valve>,
+//     current_value: u32,
+//     me: (&str, u32),
+//     elephant: (&str, u32),
+//     closed_valves: &mut BTreeSet<&str>,
+// ) -> u32 {
+//     let small = if me.1 < elephant.1 { &me } else { &elephant };
+//     let big = if me.1 >= elephant.1 { &me } else { &elephant };
+
+//     // filter out all possible targets
+//     let small_paths = valves[small.0]
+//         .paths
+//         .iter()
+//         .filter_map(|(p, d)| {
+//             if closed_valves.contains(p.as_str()) || *d + 1 > small.1 {
+//                 return None;
+//             }
+//             Some((p, *d, valves[p].flow))
+//         })
+//         .collect::<Vec<_>>();
+
+//     // jump to the single path implementation if one person has exhausted its possible paths
+//     if small_paths.is_empty() {
+//         return search(valves, current_value, big.0, big.1, closed_valves);
+//     }
+
+//     // let mut result = current_value;
+
+//     let small_best = small_paths
+//         .iter()
+//         .map(|(p, d, f)| {
+//             let new_minutes = small.1 - *d - 1;
+//             // add the new valve to the closed ones
+//             let mut new_closed = closed_valves.clone();
+//             new_closed.insert(p.as_str());
+
+//             // recursevily calculate the value for the newly closed valve
+//             search_elephant(
+//                 valves,
+//                 current_value + new_minutes * f,
+//                 (p, new_minutes),
+//                 (big.0, big.1),
+//                 &mut new_closed,
+//             )
+//         })
+//         .max();
+
+//     let big_paths = valves[big.0]
+//         .paths
+//         .iter()
+//         .filter_map(|(p, d)| {
+//             if closed_valves.contains(p.as_str()) || *d + 1 > small.1 {
+//                 return None;
+//             }
+//             Some((p, *d, valves[p].flow))
+//         })
+//         .collect::<Vec<_>>();
+
+//     let big_best = big_paths
+//         .iter()
+//         .map(|(p, d, f)| {
+//             let new_minutes = big.1 - *d - 1;
+//             // add the new valve to the closed ones
+//             let mut new_closed = closed_valves.clone();
+//             new_closed.insert(p.as_str());
+
+//             // recursevily calculate the value for the newly closed valve
+//             search_elephant(
+//                 valves,
+//                 current_value + new_minutes * f,
+//                 (small.0, small.1),
+//                 (p, new_minutes),
+//                 &mut new_closed,
+//             )
+//         })
+//         .max();
+
+//     match (small_best, big_best) {
+//         (None, None) => current_value,
+//         (None, Some(e)) => e,
+//         (Some(m), None) => m,
+//         (Some(m), Some(e)) => m.max(e),
+//     }
+// }
+
+// the valves worth opening, part 2 splits them between me and the elephant
+fn useful_valves(valves: &HashMap<String, Valve>) -> Vec<&str> {
+    valves
+        .keys()
+        .filter_map(|k| {
+            if k == "AA" {
+                return None;
+            }
+            Some(k.as_str())
+        })
+        .collect()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = HashMap<String, Valve>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        build_graph(input)
+    }
+
+    fn assumptions(valves: &Self::Input, _: &()) -> Vec<Assumption> {
+        let useful = useful_valves(valves).len();
+        let start = valves["AA"].flow;
+
+        vec![
+            Assumption::check(
+                format!(
+                    "the useful valves fit in the {}-bit mask of part 2",
+                    u32::BITS
+                ),
+                useful < u32::BITS as usize,
+                || format!("{} valves have a flow rate", useful),
+            ),
+            // the search only opens the valves it walks to
+            Assumption::check("the start valve AA has no flow rate", start == 0, || {
+                format!("AA has a flow rate of {}", start)
+            }),
+        ]
+    }
+
+    fn part1(valves: &Self::Input, _: &()) -> Result<Answer> {
+        let bits = bits(&useful_valves(valves))?;
+        let result = search(valves, &bits, "AA", 30, 0, &mut Memo::new());
+
+        Ok(result.into())
+    }
+
+    fn part2(valves: &Self::Input, _: &()) -> Result<Answer> {
+        let useful_valves = useful_valves(valves);
+        // the splits are counted by a 32-bit mask
+        if useful_valves.len() >= u32::BITS as usize {
+            let message = format!(
+                "{} valves have a flow rate, part 2 splits at most {}",
+                useful_valves.len(),
+                u32::BITS - 1
+            );
+            return Err(Error::solve(Self::DAY, message));
+        }
+        let bits = bits(&useful_valves)?;
+        let all = bits.values().fold(0, |mask, bit| mask | bit);
+
+        let mut result = 0;
+        // the splits meet the same states, so they share a memo
+        let mut memo = Memo::new();
+
+        let combinations: u32 = (1 << useful_valves.len()) - 1;
+
+        for i in 0..combinations {
+            // the valves closed for one of us are the ones the other opens
+            let closed_a = i as u64;
+            let closed_b = all & !closed_a;
+
+            let round = search(valves, &bits, "AA", 26, closed_a, &mut memo)
+                + search(valves, &bits, "AA", 26, closed_b, &mut memo);
+
+            result = result.max(round);
+        }
+
+        Ok(result.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day16::part1(&Day16::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day16::part2(&Day16::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/2022/day16/example.txt");
+
+    #[test]
+    fn malformed() {
+        let input = INPUT.replace("rate=22", "rate=x");
+        let err = puzzle_1(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 16, line 8, column 24: expected a valve description, found \"x;\""
+        );
+
+        let input = INPUT.replace("valves DD, BB", "valves DD, XX");
+        let err = puzzle_2(&input).unwrap_err();
+        assert_eq!(err.location(), Some((3, 54)));
+    }
+
+    #[test]
+    fn assumptions() {
+        let valves = Day16::load(INPUT).unwrap();
+        assert!(Day16::assumptions(&valves, &()).iter().all(|a| a.holds()));
+
+        let valves =
+            Day16::load(&INPUT.replace("AA has flow rate=0", "AA has flow rate=7")).unwrap();
+        assert_eq!(
+            Day16::assumptions(&valves, &())[1].result,
+            Err("AA has a flow rate of 7".to_string())
+        );
+
+        // every valve of a long corridor has a flow rate, but AA
+        let name = |i: u8| format!("{}{}", char::from(b'A' + i / 26), char::from(b'A' + i % 26));
+        let corridor = (0..40)
+            .map(|i| {
+                let next = name(if i < 39 { i + 1 } else { i - 1 });
+                format!(
+                    "Valve {} has flow rate={}; tunnel leads to valve {}",
+                    name(i),
+                    i,
+                    next
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let valves = Day16::load(&corridor).unwrap();
+        assert_eq!(
+            Day16::assumptions(&valves, &())[0].result,
+            Err("39 valves have a flow rate".to_string())
+        );
+        assert_eq!(
+            Day16::part2(&valves, &()),
+            Err(Error::solve(
+                Day16::DAY,
+                "39 valves have a flow rate, part 2 splits at most 31"
+            ))
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use std::fmt::Display;
+
+use common::{observe, Answer, Error, Observer, Result, Solution};
+
+#[derive(Debug, Copy, Clone)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Stone {
+    Horizontal,
+    Plus,
+    Corner,
+    Vertical,
+    Square,
+}
+
+impl Stone {
+    pub fn lines(&self, x: usize) -> Vec<u8> {
+        match self {
+            Stone::Horizontal => vec![0b1111000 >> x],
+            Stone::Plus => vec![0b0100000 >> x, 0b1110000 >> x, 0b0100000 >> x],
+            Stone::Corner => vec![0b1110000 >> x, 0b0010000 >> x, 0b0010000 >> x],
+            Stone::Vertical => vec![
+                0b1000000 >> x,
+                0b1000000 >> x,
+                0b1000000 >> x,
+                0b1000000 >> x,
+            ],
+            Stone::Square => vec![0b1100000 >> x, 0b1100000 >> x],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        match self {
+            Stone::Horizontal => 4,
+            Stone::Plus => 3,
+            Stone::Corner => 3,
+            Stone::Vertical => 1,
+            Stone::Square => 2,
+        }
+    }
+}
+
+fn parse_jets(input: &str) -> Result<Vec<Jet>> {
+    if input.is_empty() {
+        return Err(Error::parse(Day17::DAY, input, input, "a jet pattern"));
+    }
+
+    input
+        .char_indices()
+        .map(|(i, v)| match v {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err(Error::parse(Day17::DAY, input, &input[i..], "'<' or '>'")),
+        })
+        .collect::<Result<Vec<_>>>()
+}
+
+/// The rows of the settled stones, only the top few thousand are kept.
+pub struct Tower {
+    lines: Vec<u8>,
+    removed_lines: usize,
+}
+
+impl Tower {
+    fn new() -> Self {
+        Tower {
+            lines: Vec::new(),
+            removed_lines: 0,
+        }
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn get_total_height(&self) -> usize {
+        self.lines.len() + self.removed_lines
+    }
+
+    fn check_collision(&self, stone: Stone, pos: (usize, usize)) -> bool {
+        let h = self.lines.len();
+        let y = pos.1;
+
+        if h <= y {
+            return false;
+        }
+
+        let col = stone.lines(pos.0);
+
+        (0..col.len()).any(|i| y + i < h && (col[i] & self.lines[y + i]) != 0)
+    }
+
+    fn add_stone(&mut self, stone: Stone, pos: (usize, usize)) {
+        let h = self.lines.len();
+        let y = pos.1;
+
+        let col = stone.lines(pos.0);
+
+        for (i, line) in col.into_iter().enumerate() {
+            if y + i < h {
+                self.lines[y + i] |= line;
+            } else {
+                self.lines.push(line);
+            }
+        }
+
+        if self.lines.len() > 8000 {
+            self.lines.drain(0..4000);
+            self.removed_lines += 4000;
+        }
+    }
+}
+
+impl Display for Tower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for i in (0..self.lines.len()).rev() {
+            let line = (0..7)
+                .map(|b| {
+                    if (0b1000000 >> b) & self.lines[i] != 0 {
+                        return '#';
+                    }
+                    '.'
+                })
+                .collect::<String>();
+
+            writeln!(f, "{}", line)?
+        }
+        Ok(())
+    }
+}
+
+const STONE_COUNT: usize = 1000000000000;
+
+/// Drops `count` stones and returns the height of the tower after each of them,
+/// the observer sees the tower whenever another stone comes to rest.
+pub fn drop_stones(
+    jets: &[Jet],
+    count: usize,
+    observer: &mut impl Observer<Tower>,
+) -> Result<Vec<usize>> {
+    let mut jets = jets.iter().cycle();
+
+    let stones = [
+        Stone::Horizontal,
+        Stone::Plus,
+        Stone::Corner,
+        Stone::Vertical,
+        Stone::Square,
+    ]
+    .into_iter()
+    .cycle()
+    .take(count);
+
+    let mut tower = Tower::new();
+    let mut heights = Vec::with_capacity(count);
+
+    for (step, stone) in stones.enumerate() {
+        let mut y = tower.get_height() + 3;
+        let mut x = 2;
+
+        loop {
+            // cyclic iterator so unwrap is ok
+            let jet = jets.next().unwrap();
+
+            // jet move
+            match jet {
+                Jet::Left => {
+                    if x > 0 && !tower.check_collision(stone, (x - 1, y)) {
+                        x -= 1;
+                    }
+                }
+                Jet::Right => {
+                    let w = stone.width();
+                    if x + w <= 6 && !tower.check_collision(stone, (x + 1, y)) {
+                        x += 1;
+                    }
+                }
+            }
+
+            // down move
+            if y > 0 && !tower.check_collision(stone, (x, y - 1)) {
+                y -= 1;
+            } else {
+                tower.add_stone(stone, (x, y));
+                break;
+            }
+        }
+
+        heights.push(tower.get_total_height());
+        observe(Day17::DAY, observer, step + 1, &tower)?;
+    }
+
+    Ok(heights)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Vec<Jet>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_jets(input)
+    }
+
+    fn part1(jets: &Self::Input, _: &()) -> Result<Answer> {
+        let heights = drop_stones(jets, 2022, &mut ())?;
+
+        Ok(heights[heights.len() - 1].into())
+    }
+
+    fn part2(jets: &Self::Input, _: &()) -> Result<Answer> {
+        let heights = drop_stones(jets, jets.len() * 20, &mut ())?;
+
+        let diffs = heights.windows(2).map(|p| p[1] - p[0]).collect::<Vec<_>>();
+
+        // look for a long repeating sequence of stones which add the same amount of height
+        let mut pattern_start = 0;
+        let mut pattern_length = 0;
+        'outer: for chunk_size in (6..diffs.len() / 2).rev() {
+            for offset in 0..diffs.len() - 2 * chunk_size {
+                let mut chunks = diffs[offset..].chunks_exact(chunk_size);
+
+                let a = chunks.next().unwrap();
+                let b = chunks.next().unwrap();
+
+                if a == b {
+                    pattern_start = offset;
+                    pattern_length = chunk_size;
+                    break 'outer;
+                }
+            }
+        }
+
+        if pattern_length == 0 {
+            return Err(Error::solve(Self::DAY, "the tower height never repeats"));
+        }
+
+        let pattern_height = heights[pattern_start + pattern_length] - heights[pattern_start];
+        let stone_count = STONE_COUNT - pattern_start - 1;
+        let modulo = stone_count % pattern_length;
+        let result =
+            heights[pattern_start + modulo] + pattern_height * (stone_count / pattern_length);
+
+        Ok(result.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day17::part1(&Day17::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day17::part2(&Day17::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/2022/day17/example.txt");
+
+    #[test]
+    fn line_endings() {
+        // a trailing newline is not part of the sequence
+        let result = puzzle_1(&format!("{}\n", INPUT)).unwrap();
+        assert_eq!(result, Answer::Integer(3068));
+
+        let result = puzzle_1(&format!("\u{feff}{}\r\n", INPUT)).unwrap();
+        assert_eq!(result, Answer::Integer(3068));
+    }
+
+    #[test]
+    fn observed() {
+        let jets = Day17::load(INPUT).unwrap();
+
+        // the tower is drawn from the top, where the last stone rests
+        let mut drawn = |_, tower: &Tower| {
+            assert_ne!(tower.to_string().lines().next(), Some("......."));
+            std::ops::ControlFlow::Continue(())
+        };
+        assert_eq!(
+            drop_stones(&jets, 10, &mut drawn).unwrap().last(),
+            Some(&17)
+        );
+
+        let err = drop_stones(&jets, 10, &mut common::StepLimit(5)).unwrap_err();
+        assert_eq!(err.to_string(), "day 17: stopped after step 5");
+    }
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1(">>><<><>x<<").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 17, line 1, column 9: expected '<' or '>', found \"x<<\""
+        );
+
+        let err = puzzle_2("").unwrap_err();
+        assert_eq!(err.location(), Some((1, 1)));
+    }
+}
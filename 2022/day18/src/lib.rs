@@ -0,0 +1,209 @@
+use std::collections::{HashSet, VecDeque};
+
+use common::{Answer, Error, Result, Solution};
+use geometry::Point3;
+
+struct Droplet {
+    voxels: HashSet<Point3>,
+    bounding_box: (Point3, Point3),
+}
+
+impl Droplet {
+    pub fn new(voxels: HashSet<Point3>) -> Self {
+        let mut min = *voxels.iter().next().unwrap();
+        let mut max = min;
+
+        for &v in voxels.iter() {
+            min = min.min(v);
+            max = max.max(v);
+        }
+
+        Droplet {
+            voxels,
+            bounding_box: (min, max),
+        }
+    }
+
+    pub fn surface(&self) -> usize {
+        self.voxels
+            .iter()
+            .map(|v| {
+                let sides = v.neighbours6();
+
+                sides.iter().filter(|&s| !self.voxels.contains(s)).count()
+            })
+            .sum::<usize>()
+    }
+
+    fn touches_bb(&self, v: &Point3) -> bool {
+        v.x == self.bounding_box.0.x
+            || v.x == self.bounding_box.1.x
+            || v.y == self.bounding_box.0.y
+            || v.y == self.bounding_box.1.y
+            || v.z == self.bounding_box.0.z
+            || v.z == self.bounding_box.1.z
+    }
+
+    pub fn flood_fill(&self, start: &Point3) -> Option<HashSet<Point3>> {
+        let mut queue = VecDeque::new();
+        let mut filled = HashSet::new();
+
+        queue.push_back(*start);
+
+        while !queue.is_empty() {
+            let v = queue.pop_front().unwrap();
+
+            if !(filled.contains(&v) || self.voxels.contains(&v)) {
+                // skip the ones that touch the bounding box
+                // as the would count towards the outside
+                if self.touches_bb(&v) {
+                    return None;
+                }
+
+                for n in v.neighbours6() {
+                    queue.push_back(n);
+                }
+
+                filled.insert(v);
+            }
+        }
+
+        if filled.is_empty() {
+            return None;
+        }
+        Some(filled)
+    }
+
+    pub fn inner_cube(&self) -> CubeIterator {
+        let one = Point3::new(1, 1, 1);
+
+        CubeIterator::new((self.bounding_box.0 + one, self.bounding_box.1 - one))
+    }
+
+    pub fn add_voxels(&mut self, voxels: HashSet<Point3>) {
+        for v in voxels {
+            self.voxels.insert(v);
+        }
+    }
+}
+
+struct CubeIterator {
+    bb: (Point3, Point3),
+    current: Point3,
+}
+
+impl CubeIterator {
+    pub fn new(bb: (Point3, Point3)) -> Self {
+        let current = bb.0 - Point3::new(1, 0, 0);
+
+        CubeIterator { bb, current }
+    }
+}
+
+impl Iterator for CubeIterator {
+    type Item = Point3;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.x += 1;
+        if self.current.x > self.bb.1.x {
+            self.current.x = self.bb.0.x;
+            self.current.y += 1;
+            if self.current.y > self.bb.1.y {
+                self.current.y = self.bb.0.y;
+                self.current.z += 1;
+            }
+        }
+
+        if self.current.z <= self.bb.1.z {
+            return Some(self.current);
+        }
+
+        None
+    }
+}
+
+fn parse_voxels(input: &str) -> Result<HashSet<Point3>> {
+    if input.is_empty() {
+        return Err(Error::parse(Day18::DAY, input, input, "a voxel"));
+    }
+
+    input
+        .lines()
+        .map(|l| {
+            let mut coords = l.split(',');
+            let mut coord = || {
+                let n = coords.next().unwrap_or(&l[l.len()..]);
+                n.parse::<i32>()
+                    .map_err(|_| Error::parse(Day18::DAY, input, n, "a coordinate"))
+            };
+            let voxel = Point3::new(coord()?, coord()?, coord()?);
+
+            if let Some(n) = coords.next() {
+                return Err(Error::parse(Day18::DAY, input, n, "end of line"));
+            }
+
+            Ok(voxel)
+        })
+        .collect()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = HashSet<Point3>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_voxels(input)
+    }
+
+    fn part1(voxels: &Self::Input, _: &()) -> Result<Answer> {
+        let droplet = Droplet::new(voxels.clone());
+
+        let open_sides = droplet.surface();
+
+        Ok(open_sides.into())
+    }
+
+    fn part2(voxels: &Self::Input, _: &()) -> Result<Answer> {
+        let mut droplet = Droplet::new(voxels.clone());
+
+        // fill all holes
+        for v in droplet.inner_cube() {
+            if let Some(fill) = droplet.flood_fill(&v) {
+                droplet.add_voxels(fill);
+            }
+        }
+
+        let result = droplet.surface();
+
+        Ok(result.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day18::part1(&Day18::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day18::part2(&Day18::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1("2,2,2\n1,2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 18, line 2, column 4: expected a coordinate, found end of input"
+        );
+
+        let err = puzzle_2("2,2,2\n1,2,2,5").unwrap_err();
+        assert_eq!(err.location(), Some((2, 7)));
+    }
+}
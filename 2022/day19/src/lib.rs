@@ -1,23 +1,25 @@
-use cached::proc_macro::cached;
+use std::collections::HashMap;
+
+use common::{Answer, Error, Result, Solution};
 use rayon::prelude::*;
 
 use nom::{
     bytes::complete::tag,
     character::complete,
-    multi::separated_list1,
-    sequence::{preceded, tuple},
+    combinator::all_consuming,
+    sequence::{preceded, terminated, tuple},
     IResult, Parser,
 };
 
-enum Bot {
+pub enum Bot {
     Ore,
     Clay,
     Obsidian,
     Geode,
 }
 
-#[derive(Clone)]
-struct Resources {
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Resources {
     ore: u16,
     clay: u16,
     obs: u16,
@@ -48,7 +50,7 @@ impl Default for Resources {
 }
 
 #[derive(Debug)]
-struct Blueprint {
+pub struct Blueprint {
     id: u16,
     ore_bot: u16,
     clay_bot: u16,
@@ -132,9 +134,8 @@ impl Blueprint {
     }
 }
 
-fn parse_blueprints(input: &str) -> IResult<&str, Vec<Blueprint>> {
-    separated_list1(
-        tag(" obsidian.\n"),
+fn blueprint(input: &str) -> IResult<&str, Blueprint> {
+    terminated(
         tuple((
             preceded(tag("Blueprint "), complete::u16),
             preceded(tag(": Each ore robot costs "), complete::u16),
@@ -143,78 +144,117 @@ fn parse_blueprints(input: &str) -> IResult<&str, Vec<Blueprint>> {
             preceded(tag(" ore and "), complete::u16),
             preceded(tag(" clay. Each geode robot costs "), complete::u16),
             preceded(tag(" ore and "), complete::u16),
-        ))
-        .map(|t| Blueprint::new(t.0, t.1, t.2, (t.3, t.4), (t.5, t.6))),
-    )(input)
+        )),
+        tag(" obsidian."),
+    )
+    .map(|t| Blueprint::new(t.0, t.1, t.2, (t.3, t.4), (t.5, t.6)))
+    .parse(input)
+}
+
+fn parse_blueprints(input: &str) -> Result<Vec<Blueprint>> {
+    input
+        .lines()
+        .map(|line| {
+            all_consuming(blueprint)(line)
+                .map(|(_, bp)| bp)
+                .map_err(|e| Error::nom(Day19::DAY, input, e, "a blueprint"))
+        })
+        .collect()
 }
 
-#[cached(
-    key = "String",
-    convert = r#"{
-        format!("{}-{}-{}-{}-{}-{}-{}-{}-{}", bp.id, resources.ore, resources.clay, resources.obs, resources.ore_b, resources.clay_b, resources.obs_b, minutes, geodes)
-    }"#
-)]
-fn search(bp: &Blueprint, mut resources: Resources, minutes: u16, geodes: u16) -> u16 {
+/// Results of the states already searched for a single blueprint.
+type Memo = HashMap<(Resources, u16, u16), u16>;
+
+fn search(bp: &Blueprint, resources: Resources, minutes: u16, geodes: u16, memo: &mut Memo) -> u16 {
     if minutes == 0 {
         return geodes;
     }
 
+    let key = (resources, minutes, geodes);
+    if let Some(&result) = memo.get(&key) {
+        return result;
+    }
+    let (mut resources, minutes, geodes) = key.clone();
+
     let mut result = geodes;
 
     if let Some(new_resources) = bp.should_build_bot(&resources, Bot::Geode) {
         let new_geodes = geodes + (minutes - 1);
-        result = result.max(search(bp, new_resources, minutes - 1, new_geodes));
+        result = result.max(search(bp, new_resources, minutes - 1, new_geodes, memo));
     } else if let Some(new_resources) = bp.should_build_bot(&resources, Bot::Obsidian) {
-        result = result.max(search(bp, new_resources, minutes - 1, geodes));
+        result = result.max(search(bp, new_resources, minutes - 1, geodes, memo));
     } else if let Some(new_resources) = bp.should_build_bot(&resources, Bot::Clay) {
-        result = result.max(search(bp, new_resources, minutes - 1, geodes));
+        result = result.max(search(bp, new_resources, minutes - 1, geodes, memo));
     }
 
     if let Some(new_resources) = bp.should_build_bot(&resources, Bot::Ore) {
-        result = result.max(search(bp, new_resources, minutes - 1, geodes));
+        result = result.max(search(bp, new_resources, minutes - 1, geodes, memo));
     }
 
     resources.tick();
-    result = result.max(search(bp, resources, minutes - 1, geodes));
+    result = result.max(search(bp, resources, minutes - 1, geodes, memo));
 
+    memo.insert(key, result);
     result
 }
 
-pub fn puzzle_1(input: &str) -> String {
-    let (_, blueprints) = parse_blueprints(input).unwrap();
+pub struct Day19;
 
-    let result = blueprints
-        .par_iter()
-        .map(|bp| search(bp, Resources::default(), 24, 0) * bp.id)
-        .sum::<u16>();
+impl Solution for Day19 {
+    const DAY: u8 = 19;
 
-    result.to_string()
-}
+    type Input = Vec<Blueprint>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_blueprints(input)
+    }
+
+    fn part1(blueprints: &Self::Input, _: &()) -> Result<Answer> {
+        let result = blueprints
+            .par_iter()
+            .map(|bp| search(bp, Resources::default(), 24, 0, &mut Memo::new()) * bp.id)
+            .sum::<u16>();
+
+        Ok(result.into())
+    }
 
-pub fn puzzle_2(input: &str) -> String {
-    let (_, blueprints) = parse_blueprints(input).unwrap();
+    fn part2(blueprints: &Self::Input, _: &()) -> Result<Answer> {
+        let result = blueprints
+            .par_iter()
+            .take(3)
+            .map(|bp| search(bp, Resources::default(), 32, 0, &mut Memo::new()) as u32)
+            .product::<u32>();
 
-    let result = blueprints
-        .par_iter()
-        .take(3)
-        .map(|bp| search(bp, Resources::default(), 32, 0) as u32)
-        .product::<u32>();
+        Ok(result.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day19::part1(&Day19::load(input)?, &())
+}
 
-    result.to_string()
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day19::part2(&Day19::load(input)?, &())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "\
-Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
-Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+    const INPUT: &str = include_str!("../../../examples/2022/day19/example.txt");
 
     #[test]
-    #[ignore]
-    fn p1() {
-        let result = puzzle_1(INPUT);
-        assert_eq!(result, "33");
+    fn malformed() {
+        let input = INPUT.replace("3 ore and 8 clay", "3 ore and 8 obsidian");
+        let err = puzzle_1(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 19, line 2, column 108: expected a blueprint, found \" obsidian.\""
+        );
+
+        let input = INPUT.trim_end();
+        let err = puzzle_2(&input[..input.len() - 10]).unwrap_err();
+        assert_eq!(err.location(), Some((2, 150)));
     }
 }
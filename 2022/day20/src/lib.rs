@@ -0,0 +1,185 @@
+use common::{Answer, Error, Result, Solution};
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Element {
+    id: usize,
+    value: i64,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Element>> {
+    let elements = input
+        .split('\n')
+        .enumerate()
+        .map(|l| {
+            Ok(Element {
+                id: l.0,
+                value: l
+                    .1
+                    .parse::<i64>()
+                    .map_err(|_| Error::parse(Day20::DAY, input, l.1, "a number"))?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // the grove coordinates are counted from the 0
+    if !elements.iter().any(|e| e.value == 0) {
+        return Err(Error::solve(Day20::DAY, "the file doesn't contain a 0"));
+    } else if elements.len() < 2 {
+        return Err(Error::solve(Day20::DAY, "there is nothing to mix"));
+    }
+
+    Ok(elements)
+}
+
+const KEY: i64 = 811589153;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Vec<Element>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(elements: &Self::Input, _: &()) -> Result<Answer> {
+        let mut elements = elements.clone();
+        let original_order = elements.clone();
+        let rem = original_order.len() as isize - 1;
+
+        for el in original_order.iter() {
+            let index = elements
+                .iter()
+                .position(|s| {
+                    if el == s {
+                        return true;
+                    }
+                    false
+                })
+                .unwrap();
+
+            let current = elements.remove(index);
+
+            let new_pos = (index as isize) + (current.value as isize);
+            let new_pos = new_pos.rem_euclid(rem) as usize;
+
+            elements.insert(new_pos, current);
+        }
+
+        let mut last_pos = None;
+        let result = elements
+            .iter()
+            .cycle()
+            .enumerate()
+            .filter_map(|(index, el)| {
+                if let Some(pos) = last_pos {
+                    if index == pos + 1000 {
+                        last_pos = Some(index);
+                        return Some(el.value);
+                    }
+                } else {
+                    // search for 0
+                    if el.value == 0 {
+                        last_pos = Some(index);
+                    }
+                }
+                None
+            })
+            .take(3)
+            .sum::<i64>();
+
+        Ok(result.into())
+    }
+
+    fn part2(elements: &Self::Input, _: &()) -> Result<Answer> {
+        let mut elements = elements.clone();
+        let original_order = elements.clone();
+        let rem = original_order.len() as isize - 1;
+
+        for el in elements.iter_mut() {
+            el.value *= KEY;
+        }
+
+        for _ in 0..10 {
+            for el in original_order.iter() {
+                let index = elements
+                    .iter()
+                    .position(|s| {
+                        if el.id == s.id {
+                            return true;
+                        }
+                        false
+                    })
+                    .unwrap();
+
+                let current = elements.remove(index);
+
+                let new_pos = (index as isize) + (current.value as isize);
+                let new_pos = new_pos.rem_euclid(rem) as usize;
+
+                elements.insert(new_pos, current);
+            }
+        }
+
+        let mut last_pos = None;
+        let result = elements
+            .iter()
+            .cycle()
+            .enumerate()
+            .filter_map(|(index, el)| {
+                if let Some(pos) = last_pos {
+                    if index == pos + 1000 {
+                        last_pos = Some(index);
+                        return Some(el.value);
+                    }
+                } else {
+                    // search for 0
+                    if el.value == 0 {
+                        last_pos = Some(index);
+                    }
+                }
+                None
+            })
+            .take(3)
+            .sum::<i64>();
+
+        Ok(result.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day20::part1(&Day20::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day20::part2(&Day20::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/2022/day20/example.txt");
+
+    #[test]
+    fn line_endings() {
+        let input = INPUT.replace('\n', "\r\n") + "\r\n";
+        let result = puzzle_1(&input).unwrap();
+        assert_eq!(result, Answer::Integer(3));
+    }
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1("1\n2\n-3\n3\n-2\n0\n\n4").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 20, line 7, column 1: expected a number, found end of line"
+        );
+
+        let err = puzzle_2("1\n2\n-3").unwrap_err();
+        assert_eq!(err.to_string(), "day 20: the file doesn't contain a 0");
+    }
+}
@@ -0,0 +1,126 @@
+use proptest::prelude::*;
+
+/// What a monkey yells, as the tree of monkeys it waits for.
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Humn,
+    Value(i64),
+    Op(Box<Expr>, char, Box<Expr>),
+}
+
+impl Expr {
+    /// The number yelled if the humn yells `humn`, `None` once it overflows.
+    pub fn eval(&self, humn: i64) -> Option<i64> {
+        match self {
+            Expr::Humn => Some(humn),
+            Expr::Value(v) => Some(*v),
+            Expr::Op(a, op, b) => {
+                let (a, b) = (a.eval(humn)?, b.eval(humn)?);
+                match op {
+                    '+' => a.checked_add(b),
+                    '-' => a.checked_sub(b),
+                    '*' => a.checked_mul(b),
+                    _ => a.checked_div(b),
+                }
+            }
+        }
+    }
+
+    // adds the jobs of the tree and returns the name of the monkey at its top
+    fn add_jobs(&self, humn: i64, jobs: &mut Vec<String>) -> String {
+        let job = match self {
+            Expr::Humn => {
+                jobs.push(format!("humn: {}", humn));
+                return "humn".to_string();
+            }
+            Expr::Value(v) => v.to_string(),
+            Expr::Op(a, op, b) => {
+                let (a, b) = (a.add_jobs(humn, jobs), b.add_jobs(humn, jobs));
+                format!("{} {} {}", a, op, b)
+            }
+        };
+
+        // names starting with a `z` don't clash with the root or the humn
+        let i = jobs.len();
+        let name = [i / 676, i / 26, i].map(|n| char::from(b'a' + (n % 26) as u8));
+        let name = format!("z{}", String::from_iter(name));
+        jobs.push(format!("{}: {}", name, job));
+
+        name
+    }
+}
+
+/// A riddle of part 2 with its sides, the number the humn yells in part 1 and the input.
+#[derive(Debug, Clone)]
+pub struct Riddle {
+    pub sides: (Expr, Expr),
+    pub humn: i64,
+    pub input: String,
+}
+
+// numbers that don't wait for the humn, a division by zero becomes an addition
+fn constant(depth: u32) -> impl Strategy<Value = Expr> {
+    let leaf = (1..=5i64).prop_map(Expr::Value);
+
+    leaf.prop_recursive(depth, 1 << depth, 2, |inner| {
+        let op = prop::sample::select(&['+', '-', '*', '/'][..]);
+        (inner.clone(), op, inner).prop_map(|(a, op, b)| match (op, b.eval(0)) {
+            ('/', Some(0)) => Expr::Op(Box::new(a), '+', Box::new(b)),
+            _ => Expr::Op(Box::new(a), op, Box::new(b)),
+        })
+    })
+}
+
+/// The humn somewhere below one side of the root and the other side matching it
+/// for a humn number between 0 and 1000.
+///
+/// The path from the root to the humn only adds, subtracts and multiplies by
+/// numbers other than 0, so there is a single number that solves part 2.
+pub fn riddle() -> impl Strategy<Value = Riddle> {
+    let step = (
+        prop::sample::select(&['+', '-', '*'][..]),
+        constant(1),
+        any::<bool>(),
+    );
+
+    (
+        prop::collection::vec(step, 0..6),
+        constant(2),
+        0..=1000i64,
+        0..=1000i64,
+        any::<bool>(),
+    )
+        .prop_map(|(path, other, answer, humn, swap)| {
+            let mut side = Expr::Humn;
+            for (op, c, left) in path {
+                let op = match (op, c.eval(0)) {
+                    ('*', Some(0)) => '+',
+                    _ => op,
+                };
+                side = match left {
+                    true => Expr::Op(Box::new(side), op, Box::new(c)),
+                    false => Expr::Op(Box::new(c), op, Box::new(side)),
+                };
+            }
+
+            // the other side gets the number the humn's side yells for the answer
+            let target = side.eval(answer).unwrap() - other.eval(0).unwrap();
+            let other = Expr::Op(Box::new(other), '+', Box::new(Expr::Value(target)));
+            let sides = if swap { (other, side) } else { (side, other) };
+
+            let mut jobs = Vec::new();
+            let (a, b) = (
+                sides.0.add_jobs(humn, &mut jobs),
+                sides.1.add_jobs(humn, &mut jobs),
+            );
+            jobs.push(format!("root: {} + {}", a, b));
+
+            (sides, humn, jobs)
+        })
+        .prop_flat_map(|(sides, humn, jobs)| (Just(sides), Just(humn), Just(jobs).prop_shuffle()))
+        .prop_map(|(sides, humn, jobs)| Riddle {
+            sides,
+            humn,
+            input: jobs.join("\n"),
+        })
+}
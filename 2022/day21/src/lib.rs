@@ -0,0 +1,444 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{Answer, Assumption, Error, Result, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1},
+    combinator::all_consuming,
+    sequence::tuple,
+    IResult, Parser,
+};
+
+#[cfg(test)]
+mod generate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Equal,
+}
+
+#[derive(Debug, Clone)]
+pub enum Job {
+    Value(i64),
+    Expression(String, String, Op),
+    Wrong,
+    Success,
+}
+
+fn parse_job(input: &str) -> IResult<&str, Job> {
+    alt((
+        complete::i64.map(Job::Value),
+        tuple((
+            alpha1,
+            alt((tag(" + "), tag(" - "), tag(" * "), tag(" / "))),
+            alpha1,
+        ))
+        .map(|v: (&str, &str, &str)| {
+            let op = match v.1 {
+                " + " => Op::Add,
+                " - " => Op::Subtract,
+                " * " => Op::Multiply,
+                _ => Op::Divide,
+            };
+            Job::Expression(v.0.to_string(), v.2.to_string(), op)
+        }),
+    ))(input)
+}
+
+// the first wait closing a circle, as the monkey and the one it waits for, `done`
+// tells the monkeys still being followed from the ones without a circle
+fn circle<'a>(
+    waits: &HashMap<&'a str, [&'a str; 2]>,
+    id: &'a str,
+    done: &mut HashMap<&'a str, bool>,
+) -> Option<(&'a str, &'a str)> {
+    done.insert(id, false);
+    for &other in waits.get(id).into_iter().flatten() {
+        match done.get(other) {
+            Some(false) => return Some((id, other)),
+            Some(true) => {}
+            None => {
+                if let Some(found) = circle(waits, other, done) {
+                    return Some(found);
+                }
+            }
+        }
+    }
+    done.insert(id, true);
+
+    None
+}
+
+fn parse_monkeys(input: &str) -> Result<HashMap<String, Job>> {
+    let monkeys = input
+        .lines()
+        .map(|l| {
+            let (id, job) = l
+                .split_once(": ")
+                .ok_or_else(|| Error::parse(Day21::DAY, input, l, "a monkey name and job"))?;
+
+            let (_, job) = all_consuming(parse_job)(job)
+                .map_err(|e| Error::nom(Day21::DAY, input, e, "a number or an operation"))?;
+
+            Ok((l, id.to_string(), job))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // every operation has to refer to known monkeys
+    let ids = monkeys
+        .iter()
+        .map(|(_, id, _)| id.as_str())
+        .collect::<HashSet<_>>();
+    for (l, _, job) in monkeys.iter() {
+        if let Job::Expression(a, b, _) = job {
+            for other in [a, b] {
+                if !ids.contains(other.as_str()) {
+                    let at = &l[l.rfind(other.as_str()).unwrap_or(0)..];
+                    return Err(Error::parse(Day21::DAY, input, at, "a known monkey"));
+                }
+            }
+        }
+    }
+
+    // monkeys waiting for each other in a circle never yell
+    let waits = monkeys
+        .iter()
+        .filter_map(|(_, id, job)| match job {
+            Job::Expression(a, b, _) => Some((id.as_str(), [a.as_str(), b.as_str()])),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    let mut done = HashMap::new();
+    for (_, id, _) in monkeys.iter() {
+        if done.contains_key(id.as_str()) {
+            continue;
+        }
+        if let Some((from, to)) = circle(&waits, id, &mut done) {
+            let (l, _, _) = monkeys.iter().find(|(_, id, _)| id == from).unwrap();
+            let at = &l[l.rfind(to).unwrap_or(0)..];
+            let expected = "a monkey that doesn't wait for itself";
+            return Err(Error::parse(Day21::DAY, input, at, expected));
+        }
+    }
+
+    let monkeys = monkeys
+        .into_iter()
+        .map(|(_, id, job)| (id, job))
+        .collect::<HashMap<String, Job>>();
+
+    if !matches!(monkeys.get("root"), Some(Job::Expression(..))) {
+        let message = "the root monkey has to wait for two other monkeys";
+        return Err(Error::solve(Day21::DAY, message));
+    } else if !monkeys.contains_key("humn") {
+        return Err(Error::solve(Day21::DAY, "there is no humn"));
+    }
+
+    Ok(monkeys)
+}
+
+fn prepare_monkeys_2(monkeys: &HashMap<String, Job>) -> HashMap<String, Job> {
+    monkeys
+        .iter()
+        .map(|(id, job)| {
+            if id == "root" {
+                if let Job::Expression(a, b, _) = job {
+                    return (id.clone(), Job::Expression(a.clone(), b.clone(), Op::Equal));
+                }
+            } else if id == "humn" {
+                return (id.clone(), Job::Wrong);
+            }
+
+            (id.clone(), job.clone())
+        })
+        .collect()
+}
+
+fn overflows(id: &str) -> Error {
+    Error::solve(Day21::DAY, format!("the number of {} overflows", id))
+}
+
+fn divide(id: &str, a: i64, b: i64) -> Result<i64> {
+    if b == 0 {
+        return Err(Error::solve(Day21::DAY, format!("{} divides by zero", id)));
+    }
+
+    a.checked_div(b).ok_or_else(|| overflows(id))
+}
+
+// the job of a monkey once the numbers of both monkeys it waits for are known
+fn resolve_job(id: &str, a: i64, b: i64, op: Op) -> Result<Job> {
+    let value = match op {
+        Op::Add => a.checked_add(b),
+        Op::Subtract => a.checked_sub(b),
+        Op::Multiply => a.checked_mul(b),
+        Op::Divide => Some(divide(id, a, b)?),
+        Op::Equal if a == b => return Ok(Job::Success),
+        Op::Equal => return Ok(Job::Wrong),
+    };
+
+    value.map(Job::Value).ok_or_else(|| overflows(id))
+}
+
+fn resolve_monkeys(monkeys: &mut HashMap<String, Job>) -> Result<()> {
+    loop {
+        let updates = monkeys
+            .iter()
+            .filter_map(|(id, job)| match job {
+                Job::Expression(a, b, op) => match (&monkeys[a.as_str()], &monkeys[b.as_str()]) {
+                    (&Job::Value(a), &Job::Value(b)) => {
+                        Some(resolve_job(id, a, b, *op).map(|job| (id.to_string(), job)))
+                    }
+                    _ => None,
+                },
+                Job::Value(_) | Job::Wrong | Job::Success => None,
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+
+        if updates.is_empty() {
+            return Ok(());
+        }
+
+        for (id, new_job) in updates.into_iter() {
+            let job = monkeys.get_mut(&id).unwrap();
+            *job = new_job;
+        }
+    }
+}
+
+// whether a monkey waits for the humn, directly or through others
+fn waits_for_humn<'a>(
+    monkeys: &'a HashMap<String, Job>,
+    id: &'a str,
+    memo: &mut HashMap<&'a str, bool>,
+) -> bool {
+    if let Some(&waits) = memo.get(id) {
+        return waits;
+    }
+
+    // monkeys waiting for each other in a circle never get to the humn
+    memo.insert(id, false);
+    let waits = id == "humn"
+        || match &monkeys[id] {
+            Job::Expression(a, b, _) => {
+                waits_for_humn(monkeys, a, memo) || waits_for_humn(monkeys, b, memo)
+            }
+            _ => false,
+        };
+    memo.insert(id, waits);
+
+    waits
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = HashMap<String, Job>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_monkeys(input)
+    }
+
+    fn assumptions(monkeys: &Self::Input, _: &()) -> Vec<Assumption> {
+        let mut memo = HashMap::new();
+        let reached = waits_for_humn(monkeys, "root", &mut memo);
+
+        // part 2 works its way back from the root to the humn
+        let mut both = monkeys
+            .iter()
+            .filter(|(_, job)| match job {
+                Job::Expression(a, b, _) => {
+                    waits_for_humn(monkeys, a, &mut memo) && waits_for_humn(monkeys, b, &mut memo)
+                }
+                _ => false,
+            })
+            .map(|(id, _)| id.as_str())
+            .collect::<Vec<_>>();
+        both.sort_unstable();
+
+        vec![
+            Assumption::check("the root monkey waits for the humn", reached, || {
+                "the humn's number doesn't matter to the root".to_string()
+            }),
+            Assumption::check(
+                "the humn is on a single side of every monkey",
+                both.is_empty(),
+                || format!("both sides of {} wait for the humn", both.join(", ")),
+            ),
+        ]
+    }
+
+    fn part1(monkeys: &Self::Input, _: &()) -> Result<Answer> {
+        let mut monkeys = monkeys.clone();
+
+        resolve_monkeys(&mut monkeys)?;
+
+        let result = match monkeys["root"] {
+            Job::Value(a) => a,
+            _ => return Err(Error::solve(Self::DAY, "the root monkey never yells")),
+        };
+
+        Ok(result.into())
+    }
+
+    fn part2(monkeys: &Self::Input, _: &()) -> Result<Answer> {
+        let mut monkeys = prepare_monkeys_2(monkeys);
+
+        // partially solve the jobs
+        resolve_monkeys(&mut monkeys)?;
+
+        // filter out the value monkeys that are not used anymore
+        let filtered_monkeys = monkeys
+            .iter()
+            .filter_map(|m| match m.1 {
+                Job::Value(_) => {
+                    let found = monkeys.iter().find(|m2| match m2.1 {
+                        Job::Expression(a, b, _) => a == m.0 || b == m.0,
+                        _ => false,
+                    });
+                    if found.is_some() {
+                        return Some((m.0.to_string(), m.1.clone()));
+                    }
+                    None
+                }
+                _ => Some((m.0.to_string(), m.1.clone())),
+            })
+            .collect::<HashMap<String, Job>>();
+
+        let mut c_id = "root";
+        let mut c_v: i64 = 0;
+
+        // go backwards starting from "root"
+        loop {
+            let j = &filtered_monkeys[c_id];
+            if let Job::Expression(a, b, op) = j {
+                let (value, other, left) = if let Job::Value(v) = filtered_monkeys[a] {
+                    (v, b.as_str(), true)
+                } else if let Job::Value(v) = filtered_monkeys[b] {
+                    (v, a.as_str(), false)
+                } else {
+                    let message = format!("both sides of {} depend on the humn", c_id);
+                    return Err(Error::solve(Self::DAY, message));
+                };
+
+                // undo the operation with the number of the other side
+                let undone = match op {
+                    Op::Add => c_v.checked_sub(value),
+                    Op::Subtract if left => value.checked_sub(c_v),
+                    Op::Subtract => c_v.checked_add(value),
+                    Op::Multiply => Some(divide(c_id, c_v, value)?),
+                    Op::Divide if left => Some(divide(c_id, value, c_v)?),
+                    Op::Divide => c_v.checked_mul(value),
+                    Op::Equal => Some(value),
+                };
+                c_v = undone.ok_or_else(|| overflows(c_id))?;
+                c_id = other;
+            } else if c_id == "humn" {
+                break;
+            } else {
+                let message = format!("{} doesn't depend on the humn", c_id);
+                return Err(Error::solve(Self::DAY, message));
+            }
+        }
+
+        Ok(c_v.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day21::part1(&Day21::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day21::part2(&Day21::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use generate::Riddle;
+
+    // tries every number the generator could have picked for the humn
+    fn brute_force(riddle: &Riddle) -> Option<i64> {
+        let (a, b) = &riddle.sides;
+        (0..=1000).find(|&humn| a.eval(humn) == b.eval(humn))
+    }
+
+    const INPUT: &str = include_str!("../../../examples/2022/day21/example.txt");
+
+    #[test]
+    fn malformed() {
+        let input = INPUT.replace("drzm: hmdt - zczc", "drzm: hmdt % zczc");
+        let err = puzzle_1(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 21, line 14, column 11: expected a number or an operation, found \" %\""
+        );
+
+        let input = INPUT.replace("ptdq: humn - dvpt", "ptdq: humn - dvpx");
+        let err = puzzle_2(&input).unwrap_err();
+        assert_eq!(err.location(), Some((5, 14)));
+
+        let input = "root: aaaa + bbbb\naaaa: cccc + root\ncccc: 5\nbbbb: 3\nhumn: 1";
+        let err = puzzle_2(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 21, line 2, column 14: expected a monkey that doesn't wait for itself, found \"root\""
+        );
+    }
+
+    #[test]
+    fn unsolvable() {
+        let err = puzzle_1(&INPUT.replace("lfqf: 4", "lfqf: 0")).unwrap_err();
+        assert_eq!(err, Error::solve(Day21::DAY, "pppw divides by zero"));
+
+        // the humn's number times zero can't be undone
+        let err = puzzle_2(&INPUT.replace("ljgn: 2", "ljgn: 0")).unwrap_err();
+        assert_eq!(err, Error::solve(Day21::DAY, "lgvd divides by zero"));
+
+        let input = INPUT.replace("dbpl: 5", &format!("dbpl: {}", i64::MAX));
+        let err = puzzle_1(&input).unwrap_err();
+        assert_eq!(
+            err,
+            Error::solve(Day21::DAY, "the number of sjmn overflows")
+        );
+    }
+
+    #[test]
+    fn assumptions() {
+        let monkeys = Day21::load(INPUT).unwrap();
+        assert!(Day21::assumptions(&monkeys, &()).iter().all(|a| a.holds()));
+
+        let monkeys = Day21::load(&INPUT.replace("dbpl: 5", "dbpl: humn + lgvd")).unwrap();
+        let checked = Day21::assumptions(&monkeys, &());
+        assert!(checked[0].holds());
+        assert_eq!(
+            checked[1].result,
+            Err("both sides of dbpl, root wait for the humn".to_string())
+        );
+
+        let monkeys =
+            Day21::load(&INPUT.replace("ptdq: humn - dvpt", "ptdq: dvpt - dvpt")).unwrap();
+        assert!(!Day21::assumptions(&monkeys, &())[0].holds());
+    }
+
+    proptest! {
+        #[test]
+        fn brute_forced(riddle in generate::riddle()) {
+            let (a, b) = &riddle.sides;
+            let root = a.eval(riddle.humn).unwrap() + b.eval(riddle.humn).unwrap();
+
+            prop_assert_eq!(puzzle_1(&riddle.input), Ok(root.into()));
+            prop_assert_eq!(puzzle_2(&riddle.input), Ok(brute_force(&riddle).unwrap().into()));
+        }
+    }
+}
@@ -0,0 +1,554 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+};
+
+use common::{observe, Answer, Assumption, Error, Observer, Result, Solution};
+use geometry::{Dir4, Point2};
+use grid::Grid;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    combinator::all_consuming,
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+    IResult, Parser,
+};
+use serde::{Deserialize, Deserializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Wall,
+    Floor,
+    None,
+}
+
+// the password counts the facing clockwise starting east
+fn facing(dir: Dir4) -> i32 {
+    match dir {
+        Dir4::East => 0,
+        Dir4::South => 1,
+        Dir4::West => 2,
+        Dir4::North => 3,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    tiles: Grid<Tile>,
+}
+
+impl Map {
+    pub fn new(rows: Vec<Vec<Tile>>) -> Self {
+        // the lines of the map have different lengths
+        Map {
+            tiles: Grid::from_rows(rows, Tile::None),
+        }
+    }
+
+    pub fn get_start(&self) -> Point2 {
+        let pos = self
+            .tiles
+            .row(0)
+            .iter()
+            .position(|t| *t == Tile::Floor)
+            .unwrap();
+
+        Point2::new(pos as i32, 0)
+    }
+
+    pub fn get(&self, pos: Point2) -> Tile {
+        self.tiles.get(pos).copied().unwrap_or(Tile::None)
+    }
+
+    pub fn wrap(&self, pos: Point2, dir: Dir4) -> Point2 {
+        let mut current = pos;
+        loop {
+            // move in the opposite direction
+            let next = current - dir.unit();
+
+            // until a "None" is encountered
+            if self.get(next) == Tile::None {
+                break;
+            }
+
+            current = next;
+        }
+
+        current
+    }
+}
+
+pub type CubeLookup = HashMap<(i32, i32, Dir4), (i32, i32, Dir4)>;
+
+struct Cube<'a> {
+    size: i32,
+    lookup: &'a CubeLookup,
+}
+
+impl<'a> Cube<'a> {
+    pub fn new(size: i32, lookup: &'a CubeLookup) -> Self {
+        Cube { size, lookup }
+    }
+
+    pub fn wrap(&self, pos: Point2, dir: Dir4) -> Result<(Point2, Dir4)> {
+        let m = self.size;
+        let Point2 { x, y } = pos;
+
+        let x_id = x / m;
+        let y_id = y / m;
+
+        if let Some(&(tx, ty, dir_target)) = self.lookup.get(&(x_id, y_id, dir)) {
+            use Dir4::*;
+            let (new_x, new_y) = match (dir, dir_target) {
+                (East, East) => (tx * m, ty * m + (y % m)),
+                (East, South) => {
+                    let d = (m - 1) - (y % m);
+                    (tx * m + d, ty * m)
+                }
+                (East, West) => {
+                    let d = (m - 1) - (y % m);
+                    ((tx + 1) * m - 1, ty * m + d)
+                }
+                (East, North) => (tx * m + (y % m), (ty + 1) * m - 1),
+
+                (South, East) => {
+                    let d = (m - 1) - (x % m);
+                    (tx * m, ty * m + d)
+                }
+                (South, South) => (tx * m + (x % m), ty * m),
+                (South, West) => ((tx + 1) * m - 1, ty * m + (x % m)),
+                (South, North) => {
+                    let d = (m - 1) - (x % m);
+                    (tx * m + d, (ty + 1) * m - 1)
+                }
+
+                (West, East) => {
+                    let d = (m - 1) - (y % m);
+                    (tx * m, ty * m + d)
+                }
+                (West, South) => (tx * m + (y % m), ty * m),
+                (West, West) => ((tx + 1) * m - 1, ty * m + (y % m)),
+                (West, North) => {
+                    let d = (m - 1) - (y % m);
+                    (tx * m + d, (ty + 1) * m - 1)
+                }
+
+                (North, East) => (tx * m, ty * m + (x % m)),
+                (North, South) => {
+                    let d = (m - 1) - (x % m);
+                    (tx * m + d, ty * m)
+                }
+                (North, West) => {
+                    let d = (m - 1) - (x % m);
+                    ((tx + 1) * m - 1, ty * m + d)
+                }
+                (North, North) => (tx * m + (x % m), (ty + 1) * m - 1),
+            };
+            Ok((Point2::new(new_x, new_y), dir_target))
+        } else {
+            let message = format!("missing lookup ({}, {}, {:?})", x_id, y_id, dir);
+            Err(Error::solve(Day22::DAY, message))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Move {
+    Walk(usize),
+    Left,
+    Right,
+}
+
+impl Move {
+    fn turn(&self, dir: Dir4) -> Dir4 {
+        match self {
+            Move::Left => dir.turn_left(),
+            Move::Right => dir.turn_right(),
+            Move::Walk(_) => dir,
+        }
+    }
+}
+
+fn parse_map(input: &str) -> IResult<&str, Map> {
+    separated_list1(
+        line_ending,
+        many1(alt((
+            tag(" ").map(|_| Tile::None),
+            tag("#").map(|_| Tile::Wall),
+            tag(".").map(|_| Tile::Floor),
+        ))),
+    )
+    .map(Map::new)
+    .parse(input)
+}
+
+fn parse_moves(input: &str) -> IResult<&str, Vec<Move>> {
+    many1(alt((
+        tag("L").map(|_| Move::Left),
+        tag("R").map(|_| Move::Right),
+        complete::u64.map(|n| Move::Walk(n as usize)),
+    )))(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Map, Vec<Move>)> {
+    separated_pair(parse_map, tag("\n\n"), parse_moves)(input)
+}
+
+/// Cube net of the real puzzle input, see the tests for the example.
+///
+/// In a config file the lookup is a list of edges, each a pair of
+/// `[x, y, direction]` faces, e.g. `[[1, 0, "North"], [0, 3, "East"]]`.
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub cube_size: i32,
+    #[serde(deserialize_with = "edges")]
+    pub lookup: CubeLookup,
+}
+
+fn edges<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<CubeLookup, D::Error> {
+    let edges = Vec::<((i32, i32, Dir4), (i32, i32, Dir4))>::deserialize(deserializer)?;
+
+    Ok(edges.into_iter().collect())
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        use Dir4::*;
+
+        let lookup = HashMap::from([
+            ((1, 0, North), (0, 3, East)),
+            ((0, 3, West), (1, 0, South)),
+            ((2, 0, North), (0, 3, North)),
+            ((0, 3, South), (2, 0, South)),
+            ((2, 0, East), (1, 2, West)),
+            ((1, 2, East), (2, 0, West)),
+            ((2, 0, South), (1, 1, West)),
+            ((1, 1, East), (2, 0, North)),
+            ((1, 2, South), (0, 3, West)),
+            ((0, 3, East), (1, 2, North)),
+            ((0, 2, West), (1, 0, East)),
+            ((1, 0, West), (0, 2, East)),
+            ((0, 2, North), (1, 1, East)),
+            ((1, 1, West), (0, 2, South)),
+        ]);
+
+        Params {
+            cube_size: 50,
+            lookup,
+        }
+    }
+}
+
+/// The map with the tiles walked so far, each showing the last facing on it.
+pub struct Trail {
+    pub map: Map,
+    pub position: Point2,
+    pub dir: Dir4,
+    pub visited: HashMap<Point2, Dir4>,
+}
+
+/// Draws the trail like the puzzle does, with '>', 'v', '<' and '^'.
+impl Display for Trail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.map.tiles.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            let mut line = String::with_capacity(row.len());
+            for (x, tile) in row.iter().enumerate() {
+                line.push(
+                    match (self.visited.get(&Point2::new(x as i32, y as i32)), tile) {
+                        (Some(Dir4::East), _) => '>',
+                        (Some(Dir4::South), _) => 'v',
+                        (Some(Dir4::West), _) => '<',
+                        (Some(Dir4::North), _) => '^',
+                        (None, Tile::Wall) => '#',
+                        (None, Tile::Floor) => '.',
+                        (None, Tile::None) => ' ',
+                    },
+                );
+            }
+            // the lines of the map have different lengths
+            write!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Follows the path on the map, wrapping around the flat map or the `cube`, and
+/// returns the password. The observer sees the trail after every move.
+fn walk(
+    map: &Map,
+    moves: &[Move],
+    cube: Option<&Cube>,
+    observer: &mut impl Observer<Trail>,
+) -> Result<i32> {
+    let start = map.get_start();
+    let mut trail = Trail {
+        map: map.clone(),
+        position: start,
+        dir: Dir4::East,
+        visited: HashMap::from([(start, Dir4::East)]),
+    };
+
+    for (step, m) in moves.iter().enumerate() {
+        if let Move::Walk(steps) = m {
+            for _ in 0..*steps {
+                // go a step in the direction
+                let next = trail.position + trail.dir.unit();
+
+                // check the tile
+                let (next, dir) = match map.get(next) {
+                    Tile::Wall => break,
+                    Tile::Floor => (next, trail.dir),
+                    // wrap around
+                    Tile::None => match cube {
+                        Some(cube) => cube.wrap(trail.position, trail.dir)?,
+                        None => (map.wrap(trail.position, trail.dir), trail.dir),
+                    },
+                };
+
+                // and check again
+                if map.get(next) == Tile::Wall {
+                    break;
+                }
+                trail.position = next;
+                trail.dir = dir;
+                trail.visited.insert(next, dir);
+            }
+        } else {
+            trail.dir = m.turn(trail.dir);
+            trail.visited.insert(trail.position, trail.dir);
+        }
+
+        observe(Day22::DAY, observer, step + 1, &trail)?;
+    }
+
+    let Point2 { x, y } = trail.position;
+    Ok((y + 1) * 1000 + (x + 1) * 4 + facing(trail.dir))
+}
+
+/// Follows the path on the map folded into a cube, see [`walk`].
+pub fn walk_cube(
+    map: &Map,
+    moves: &[Move],
+    params: &Params,
+    observer: &mut impl Observer<Trail>,
+) -> Result<i32> {
+    let cube = Cube::new(params.cube_size, &params.lookup);
+    walk(map, moves, Some(&cube), observer)
+}
+
+/// Follows the path on the flat map, see [`walk`].
+pub fn walk_flat(map: &Map, moves: &[Move], observer: &mut impl Observer<Trail>) -> Result<i32> {
+    walk(map, moves, None, observer)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = (Map, Vec<Move>);
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (_, notes) = all_consuming(parse_input)(input)
+            .map_err(|e| Error::nom(Self::DAY, input, e, "a map and a path description"))?;
+
+        // the path starts on the first open tile of the top row
+        if !notes.0.tiles.row(0).contains(&Tile::Floor) {
+            return Err(Error::parse(
+                Self::DAY,
+                input,
+                input,
+                "an open tile in the top row",
+            ));
+        }
+
+        Ok(notes)
+    }
+
+    fn assumptions(notes: &Self::Input, params: &Self::Params) -> Vec<Assumption> {
+        let (map, _) = notes;
+        let size = params.cube_size;
+        let net = format!("the map is a net of six {}x{} faces", size, size);
+
+        let (width, height) = (map.tiles.width() as i32, map.tiles.height() as i32);
+        if size <= 0 || width % size != 0 || height % size != 0 {
+            let found = format!("the map is {}x{}", width, height);
+            return vec![Assumption::new(net, Err(found))];
+        }
+
+        let mut faces = BTreeSet::new();
+        let mut partial = None;
+        for fy in 0..height / size {
+            for fx in 0..width / size {
+                let empty = (0..size * size)
+                    .map(|i| Point2::new(fx * size + i % size, fy * size + i / size))
+                    .filter(|&p| map.get(p) == Tile::None)
+                    .count() as i32;
+                if empty == 0 {
+                    faces.insert((fx, fy));
+                } else if empty < size * size {
+                    partial.get_or_insert((fx, fy));
+                }
+            }
+        }
+        let found = match partial {
+            Some((x, y)) => Err(format!("face ({}, {}) is partly empty", x, y)),
+            None if faces.len() != 6 => Err(format!("there are {} faces", faces.len())),
+            None => Ok(()),
+        };
+
+        // the cube only wraps the edges at the border of the net
+        let mut open = Vec::new();
+        for &(fx, fy) in faces.iter() {
+            for dir in [Dir4::East, Dir4::South, Dir4::West, Dir4::North] {
+                let Point2 { x, y } = dir.unit();
+                if faces.contains(&(fx + x, fy + y)) {
+                    continue;
+                }
+                match params.lookup.get(&(fx, fy, dir)) {
+                    Some((tx, ty, _)) if faces.contains(&(*tx, *ty)) => {}
+                    Some((tx, ty, _)) => open.push(format!(
+                        "({}, {}) {:?} leads to ({}, {}), which isn't a face",
+                        fx, fy, dir, tx, ty
+                    )),
+                    None => open.push(format!("({}, {}) {:?} leads nowhere", fx, fy, dir)),
+                }
+            }
+        }
+
+        vec![
+            Assumption::new(net, found),
+            Assumption::check(
+                "the lookup folds every edge of the net",
+                open.is_empty(),
+                || open.join(", "),
+            ),
+        ]
+    }
+
+    fn part1(notes: &Self::Input, _: &Self::Params) -> Result<Answer> {
+        let (map, moves) = notes;
+
+        Ok(walk_flat(map, moves, &mut ())?.into())
+    }
+
+    fn part2(notes: &Self::Input, params: &Self::Params) -> Result<Answer> {
+        let (map, moves) = notes;
+
+        Ok(walk_cube(map, moves, params, &mut ())?.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day22::part1(&Day22::load(input)?, &Params::default())
+}
+
+pub fn puzzle_2(input: &str, cube_size: i32, lookup: CubeLookup) -> Result<Answer> {
+    let params = Params { cube_size, lookup };
+    Day22::part2(&Day22::load(input)?, &params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/2022/day22/example.txt");
+
+    #[test]
+    fn malformed() {
+        let input = INPUT.replace("...#.......#", "...#...x...#");
+        let err = puzzle_1(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 22, line 5, column 8: expected a map and a path description, found \"x...#\""
+        );
+
+        let err = puzzle_1(&INPUT.replace("10R5", "10X5")).unwrap_err();
+        assert_eq!(err.location(), Some((14, 3)));
+    }
+
+    #[test]
+    fn observed() {
+        let (map, moves) = Day22::load(INPUT).unwrap();
+
+        let mut last = String::new();
+        let mut drawn = |_, trail: &Trail| {
+            last = trail.to_string();
+            std::ops::ControlFlow::Continue(())
+        };
+        assert_eq!(walk_flat(&map, &moves, &mut drawn), Ok(6032));
+        assert_eq!(
+            last,
+            "        >>v#
+        .#v.
+        #.v.
+        ..v.
+...#...v..v#
+>>>v...>#.>>
+..#v...#....
+...>>>>v..#.
+        ...#....
+        .....#..
+        .#......
+        ......#."
+        );
+    }
+
+    #[test]
+    fn assumptions() {
+        use Dir4::*;
+
+        let notes = Day22::load(INPUT).unwrap();
+        let checked = Day22::assumptions(&notes, &Params::default());
+        assert_eq!(checked.len(), 1);
+        assert_eq!(checked[0].result, Err("the map is 16x12".to_string()));
+
+        let mut params = Params {
+            cube_size: 4,
+            lookup: HashMap::from([
+                ((2, 0, West), (1, 1, South)),
+                ((2, 0, North), (0, 1, South)),
+                ((2, 0, East), (3, 2, West)),
+                ((2, 1, East), (3, 2, South)),
+                ((3, 2, North), (2, 1, West)),
+                ((3, 2, East), (2, 0, West)),
+                ((3, 2, South), (0, 1, East)),
+                ((2, 2, South), (0, 1, North)),
+                ((2, 2, West), (1, 1, North)),
+                ((1, 1, South), (2, 2, East)),
+                ((0, 1, South), (2, 2, North)),
+                ((0, 1, West), (3, 2, North)),
+                ((0, 1, North), (2, 0, South)),
+                ((1, 1, North), (2, 0, East)),
+            ]),
+        };
+        assert!(Day22::assumptions(&notes, &params)
+            .iter()
+            .all(|a| a.holds()));
+
+        params.lookup.remove(&(3, 2, South));
+        params.lookup.insert((2, 0, West), (0, 0, South));
+        assert_eq!(
+            Day22::assumptions(&notes, &params)[1].result,
+            Err(
+                "(2, 0) West leads to (0, 0), which isn't a face, (3, 2) South leads nowhere"
+                    .to_string()
+            )
+        );
+
+        let notes = Day22::load(&INPUT.replacen("        ...#", "       ....#", 1)).unwrap();
+        assert_eq!(
+            Day22::assumptions(&notes, &params)[0].result,
+            Err("face (1, 0) is partly empty".to_string())
+        );
+    }
+}
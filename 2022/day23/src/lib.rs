@@ -0,0 +1,194 @@
+use std::{collections::HashMap, fmt::Display};
+
+use common::{observe, Answer, Error, Observer, Result, Solution};
+use geometry::{Dir4, Point2};
+use grid::{Grid, SparseGrid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf;
+
+impl Display for Elf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#")
+    }
+}
+
+fn parse_input(input: &str) -> Result<SparseGrid<Elf>> {
+    let map = Grid::parse(Day23::DAY, input, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    let elves = map
+        .iter()
+        .filter(|(_, &elf)| elf)
+        .map(|((x, y), _)| (Point2::new(x as i32, y as i32), Elf))
+        .collect::<SparseGrid<_>>();
+
+    if elves.is_empty() {
+        return Err(Error::solve(Day23::DAY, "there are no elves"));
+    }
+
+    Ok(elves)
+}
+
+// the order in which the directions are considered, rotating every round
+const DIRECTIONS: [Dir4; 4] = [Dir4::North, Dir4::South, Dir4::West, Dir4::East];
+
+// the straight and both diagonal neighbors in a direction
+fn get_neighbors(pos: Point2, dir: Dir4) -> [Point2; 3] {
+    let ahead = pos + dir.unit();
+
+    [
+        ahead + dir.turn_left().unit(),
+        ahead,
+        ahead + dir.turn_right().unit(),
+    ]
+}
+
+/// Plays rounds until no elf moves or the `limit` is reached, and returns the elves
+/// and the number of rounds played, the observer sees the elves after every round.
+pub fn spread(
+    elves: &SparseGrid<Elf>,
+    limit: Option<usize>,
+    observer: &mut impl Observer<SparseGrid<Elf>>,
+) -> Result<(SparseGrid<Elf>, usize)> {
+    let mut elves = elves.clone();
+    let limit = limit.unwrap_or(usize::MAX);
+
+    for round in 0..limit {
+        // the 4 direction checks for this round
+        let checks = (round..(round + 4))
+            .map(|i| DIRECTIONS[i % 4])
+            .collect::<Vec<_>>();
+
+        // data structure for the proposals
+        let mut proposals = HashMap::new();
+
+        for pos in elves.positions() {
+            // check the 8 neighbors
+            if !pos.neighbours8().iter().any(|&p| elves.contains(p)) {
+                continue;
+            }
+
+            // go through the directions and check the neighbors there
+            for d in checks.iter() {
+                let side_count = get_neighbors(pos, *d)
+                    .into_iter()
+                    .filter(|&p| elves.contains(p))
+                    .count();
+
+                if side_count == 0 {
+                    // propose a move in the direction
+
+                    let new_pos = pos + d.unit();
+
+                    if let std::collections::hash_map::Entry::Vacant(e) = proposals.entry(new_pos) {
+                        // add the current pos of the elf to this field
+                        e.insert(Some(pos));
+                    } else {
+                        // multiple elves try the same field, so set it to none
+                        *proposals.get_mut(&new_pos).unwrap() = None;
+                    }
+
+                    break;
+                }
+            }
+        }
+
+        let mut moved = false;
+
+        // move the elves
+        for (target, source) in proposals.iter() {
+            if let Some(src) = source {
+                // remove the old elf's position
+                elves.remove(*src);
+
+                // add the new position
+                elves.insert(*target, Elf);
+
+                moved = true;
+            }
+        }
+
+        observe(Day23::DAY, observer, round + 1, &elves)?;
+
+        if !moved {
+            return Ok((elves, round + 1));
+        }
+    }
+
+    Ok((elves, limit))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = SparseGrid<Elf>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(elves: &Self::Input, _: &()) -> Result<Answer> {
+        let (elves, _) = spread(elves, Some(10), &mut ())?;
+
+        // find the bounding box
+        let (min, max) = elves.bounds().unwrap();
+        let size = max - min + Point2::new(1, 1);
+
+        let free_spaces = size.x * size.y - elves.len() as i32;
+
+        Ok(free_spaces.into())
+    }
+
+    fn part2(elves: &Self::Input, _: &()) -> Result<Answer> {
+        let (_, rounds) = spread(elves, None, &mut ())?;
+
+        Ok(rounds.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day23::part1(&Day23::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day23::part2(&Day23::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn observed() {
+        let elves = Day23::load(".....\n..##.\n..#..\n.....\n..##.\n.....").unwrap();
+
+        // elves never get lost or merge
+        let mut counted = |_, grid: &SparseGrid<Elf>| {
+            assert_eq!(grid.len(), 5);
+            std::ops::ControlFlow::Continue(())
+        };
+        assert_eq!(spread(&elves, None, &mut counted).unwrap().1, 4);
+
+        let err = spread(&elves, None, &mut common::StepLimit(2)).unwrap_err();
+        assert_eq!(err.to_string(), "day 23: stopped after step 2");
+    }
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1("....#..\n..###.#\n#...#o#").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 23, line 3, column 6: expected '#' or '.', found \"o#\""
+        );
+
+        let err = puzzle_2("....\n....").unwrap_err();
+        assert_eq!(err.to_string(), "day 23: there are no elves");
+    }
+}
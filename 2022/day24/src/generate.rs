@@ -0,0 +1,26 @@
+use proptest::prelude::*;
+
+/// A walled valley with the start at the top left and the goal at the bottom right.
+///
+/// No blizzard moves up or down in the columns of the start and the goal, they
+/// would blow out of the valley.
+pub fn valley() -> impl Strategy<Value = String> {
+    (1..8usize, 1..6usize)
+        .prop_flat_map(|(width, height)| {
+            let cell = prop::sample::select(&['.', '.', '.', '<', '>', '^', 'v'][..]);
+            (Just(width), prop::collection::vec(cell, width * height))
+        })
+        .prop_map(|(width, cells)| {
+            let mut lines = vec![format!("#.{}", "#".repeat(width))];
+            for row in cells.chunks(width) {
+                let row = row.iter().enumerate().map(|(x, &c)| match c {
+                    '^' | 'v' if x == 0 || x == width - 1 => '.',
+                    c => c,
+                });
+                lines.push(format!("#{}#", String::from_iter(row)));
+            }
+            lines.push(format!("{}.#", "#".repeat(width)));
+
+            lines.join("\n")
+        })
+}
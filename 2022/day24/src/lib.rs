@@ -0,0 +1,529 @@
+use std::{collections::HashSet, fmt::Display};
+
+use common::{observe, Answer, Assumption, Error, Observer, Result, Solution};
+use geometry::{Dir4, Point2};
+use grid::Grid;
+
+#[cfg(test)]
+mod generate;
+
+const START: Point2 = Point2::new(1, 0);
+
+#[derive(Clone)]
+pub struct Blizzard {
+    pos: Point2,
+    dir: Dir4,
+}
+
+#[derive(Clone)]
+pub struct Field {
+    blizzards: Vec<Blizzard>,
+    /// As they are in the input, the rounds only rely on the border.
+    walls: HashSet<Point2>,
+    width: usize,
+    height: usize,
+}
+
+impl Field {
+    pub fn new(
+        blizzards: Vec<Blizzard>,
+        walls: HashSet<Point2>,
+        width: usize,
+        height: usize,
+    ) -> Self {
+        Field {
+            blizzards,
+            walls,
+            width,
+            height,
+        }
+    }
+
+    pub fn move_blizzards(&mut self) {
+        for b in self.blizzards.iter_mut() {
+            let mut new_pos = b.pos + b.dir.unit();
+            if new_pos.x == 0 {
+                new_pos.x = (self.width - 2) as i32;
+            } else if new_pos.x == (self.width - 1) as i32 {
+                new_pos.x = 1;
+            } else if new_pos.y == 0 {
+                new_pos.y = (self.height - 2) as i32;
+            } else if new_pos.y == (self.height - 1) as i32 {
+                new_pos.y = 1;
+            }
+
+            b.pos = new_pos;
+        }
+    }
+
+    /// Rounds after which the blizzards are back where they started.
+    pub fn period(&self) -> usize {
+        let (width, height) = (self.width - 2, self.height - 2);
+        let (mut a, mut b) = (width, height);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+
+        width / a * height
+    }
+
+    pub fn get_goal(&self) -> Point2 {
+        Point2::new((self.width - 2) as i32, (self.height - 1) as i32)
+    }
+
+    /// Waiting or moving, walls are filtered out by the free spaces.
+    pub fn neighbors(&self, pos: Point2) -> [Point2; 5] {
+        let [n, e, s, w] = pos.neighbours4();
+
+        [pos, n, e, s, w]
+    }
+
+    /// Positions without a wall or blizzard.
+    pub fn free_spaces(&self) -> Grid<bool> {
+        let mut spaces = Grid::new(self.width, self.height, false);
+        for x in 1..(self.width - 1) {
+            for y in 1..(self.height - 1) {
+                spaces[(x, y)] = true;
+            }
+        }
+        spaces[(1, 0)] = true;
+        spaces[(self.width - 2, self.height - 1)] = true;
+
+        for b in self.blizzards.iter() {
+            spaces[(b.pos.x as usize, b.pos.y as usize)] = false;
+        }
+
+        spaces
+    }
+}
+
+fn parse_field(input: &str) -> Result<Field> {
+    let valley = Grid::parse(Day24::DAY, input, "a wall, ground or blizzard", |c| {
+        "#.<>^v".contains(c).then_some(c)
+    })?;
+
+    let (width, height) = (valley.width(), valley.height());
+    if height < 3 || width < 3 {
+        return Err(Error::parse(Day24::DAY, input, input, "a walled valley"));
+    }
+
+    let mut blizzards = Vec::new();
+    for ((x, y), &c) in valley.iter() {
+        let dir = match c {
+            '<' => Dir4::West,
+            '>' => Dir4::East,
+            '^' => Dir4::North,
+            'v' => Dir4::South,
+            _ => continue,
+        };
+
+        // a blizzard in the wall would never come back into the valley
+        if x == 0 || y == 0 || x == width - 1 || y == height - 1 {
+            // the grid only has single byte characters
+            let line = input
+                .lines()
+                .nth(y)
+                .expect("the grid has the lines of the input");
+            return Err(Error::parse(
+                Day24::DAY,
+                input,
+                &line[x..],
+                "ground or a wall",
+            ));
+        }
+
+        blizzards.push(Blizzard {
+            pos: Point2::new(x as i32, y as i32),
+            dir,
+        });
+    }
+
+    let walls = valley
+        .iter()
+        .filter(|(_, &c)| c == '#')
+        .map(|((x, y), _)| Point2::new(x as i32, y as i32))
+        .collect();
+
+    Ok(Field::new(blizzards, walls, width, height))
+}
+
+/// The blizzards and every position the expedition could be at.
+pub struct Expedition {
+    pub field: Field,
+    pub positions: HashSet<Point2>,
+}
+
+/// Draws the valley like the puzzle, places with several blizzards show their
+/// number and the places the expedition could be at are marked with 'E'.
+impl Display for Expedition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (width, height) = (self.field.width, self.field.height);
+        let mut cells = Grid::new(width, height, '#');
+        for (x, y) in (1..width - 1).flat_map(|x| (1..height - 1).map(move |y| (x, y))) {
+            cells[(x, y)] = '.';
+        }
+        cells[(1, 0)] = '.';
+        cells[(width - 2, height - 1)] = '.';
+
+        for b in self.field.blizzards.iter() {
+            let cell = &mut cells[(b.pos.x as usize, b.pos.y as usize)];
+            *cell = match *cell {
+                '.' => match b.dir {
+                    Dir4::North => '^',
+                    Dir4::East => '>',
+                    Dir4::South => 'v',
+                    Dir4::West => '<',
+                },
+                '2'..='8' => (*cell as u8 + 1) as char,
+                _ => '2',
+            };
+        }
+        for p in self.positions.iter() {
+            cells[(p.x as usize, p.y as usize)] = 'E';
+        }
+
+        write!(f, "{}", cells)
+    }
+}
+
+/// Crosses the valley `trips` times, going back and forth, and returns the rounds
+/// it took, the observer sees the expedition after every round.
+pub fn traverse(
+    field: &Field,
+    trips: usize,
+    observer: &mut impl Observer<Expedition>,
+) -> Result<usize> {
+    let goal = field.get_goal();
+    let mut expedition = Expedition {
+        field: field.clone(),
+        positions: HashSet::new(),
+    };
+    let mut round = 0;
+
+    for trip in 0..trips {
+        let (from, to) = if trip % 2 == 0 {
+            (START, goal)
+        } else {
+            (goal, START)
+        };
+
+        // waiting at the start keeps everything reachable one period later reachable,
+        // so the positions grow until they are the same as a period before
+        let period = field.period();
+        let mut sizes = vec![None; period];
+        let mut unchanged = 0;
+
+        expedition.positions = HashSet::from([from]);
+        while !expedition.positions.contains(&to) {
+            if expedition.positions.is_empty() || unchanged == period {
+                return Err(Error::solve(
+                    Day24::DAY,
+                    "there is no way through the valley",
+                ));
+            }
+
+            expedition.field.move_blizzards();
+            round += 1;
+
+            let free_spaces = expedition.field.free_spaces();
+
+            expedition.positions = expedition
+                .positions
+                .iter()
+                .flat_map(|&p| {
+                    expedition
+                        .field
+                        .neighbors(p)
+                        .into_iter()
+                        .filter(|&n| free_spaces.get(n) == Some(&true))
+                })
+                .collect::<HashSet<_>>();
+
+            observe(Day24::DAY, observer, round, &expedition)?;
+
+            let size = Some(expedition.positions.len());
+            if std::mem::replace(&mut sizes[round % period], size) == size {
+                unchanged += 1;
+            } else {
+                unchanged = 0;
+            }
+        }
+    }
+
+    Ok(round)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Field;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_field(input)
+    }
+
+    fn assumptions(field: &Self::Input, _: &()) -> Vec<Assumption> {
+        let (width, height) = (field.width as i32, field.height as i32);
+        let goal = field.get_goal();
+        let open = |y: i32| {
+            (0..width)
+                .filter(|&x| !field.walls.contains(&Point2::new(x, y)))
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+        };
+        let (top, bottom) = (open(0), open(height - 1));
+
+        // blizzards wrap around as soon as they reach the border
+        let mut misplaced = (1..height - 1)
+            .flat_map(|y| [Point2::new(0, y), Point2::new(width - 1, y)])
+            .filter(|p| !field.walls.contains(p))
+            .map(|p| format!("({}, {}) isn't a wall", p.x, p.y))
+            .collect::<Vec<_>>();
+        let mut inside = field
+            .walls
+            .iter()
+            .filter(|p| (1..width - 1).contains(&p.x) && (1..height - 1).contains(&p.y))
+            .map(|p| (p.y, p.x))
+            .collect::<Vec<_>>();
+        inside.sort_unstable();
+        misplaced.extend(
+            inside
+                .into_iter()
+                .map(|(y, x)| format!("({}, {}) is a wall", x, y)),
+        );
+
+        let mut blowing = field
+            .blizzards
+            .iter()
+            .filter(|b| b.pos.x == START.x || b.pos.x == goal.x)
+            .filter(|b| matches!(b.dir, Dir4::North | Dir4::South))
+            .map(|b| (b.pos.y, b.pos.x, b.dir))
+            .collect::<Vec<_>>();
+        blowing.sort_unstable_by_key(|&(y, x, _)| (y, x));
+
+        vec![
+            Assumption::check("the start is at (1, 0)", top == ["1"], || {
+                format!("the top row is open at x = {}", top.join(", "))
+            }),
+            Assumption::check(
+                format!("the goal is at ({}, {})", goal.x, goal.y),
+                bottom == [goal.x.to_string()],
+                || format!("the bottom row is open at x = {}", bottom.join(", ")),
+            ),
+            Assumption::check(
+                "the valley is surrounded by walls and has none inside",
+                misplaced.is_empty(),
+                || misplaced.join(", "),
+            ),
+            Assumption::check(
+                "no blizzard blows into the start or the goal",
+                blowing.is_empty(),
+                || {
+                    let (y, x, dir) = blowing[0];
+                    format!("the blizzard at ({}, {}) blows {:?}", x, y, dir)
+                },
+            ),
+        ]
+    }
+
+    fn part1(field: &Self::Input, _: &()) -> Result<Answer> {
+        Ok(traverse(field, 1, &mut ())?.into())
+    }
+
+    fn part2(field: &Self::Input, _: &()) -> Result<Answer> {
+        Ok(traverse(field, 3, &mut ())?.into())
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day24::part1(&Day24::load(input)?, &())
+}
+
+pub fn puzzle_2(input: &str) -> Result<Answer> {
+    Day24::part2(&Day24::load(input)?, &())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashSet, VecDeque};
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Where the blizzards are in every minute of their period.
+    struct Valley {
+        width: i32,
+        height: i32,
+        blizzards: Vec<HashSet<(i32, i32)>>,
+    }
+
+    impl Valley {
+        fn new(input: &str) -> Self {
+            let lines = input.lines().collect::<Vec<_>>();
+            let (width, height) = (lines[0].len() as i32 - 2, lines.len() as i32 - 2);
+            let period = (1..).find(|t| t % width == 0 && t % height == 0).unwrap();
+
+            let blizzards = (0..period)
+                .map(|t| {
+                    let mut taken = HashSet::new();
+                    for (y, line) in lines.iter().enumerate() {
+                        for (x, c) in line.chars().enumerate() {
+                            let (dx, dy) = match c {
+                                '>' => (1, 0),
+                                '<' => (-1, 0),
+                                'v' => (0, 1),
+                                '^' => (0, -1),
+                                _ => continue,
+                            };
+                            // inside the walls the valley starts at 0
+                            let x = (x as i32 - 1 + dx * t).rem_euclid(width);
+                            let y = (y as i32 - 1 + dy * t).rem_euclid(height);
+                            taken.insert((x + 1, y + 1));
+                        }
+                    }
+                    taken
+                })
+                .collect();
+
+            Valley {
+                width,
+                height,
+                blizzards,
+            }
+        }
+
+        // the minute of the earliest arrival, trying every move in every minute
+        fn cross(&self, from: (i32, i32), to: (i32, i32), start: usize) -> Option<usize> {
+            let period = self.blizzards.len();
+            let mut seen = HashSet::from([(from, start % period)]);
+            let mut queue = VecDeque::from([(from, start)]);
+
+            while let Some(((x, y), t)) = queue.pop_front() {
+                if (x, y) == to {
+                    return Some(t);
+                }
+                for (dx, dy) in [(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1)] {
+                    let next = (x + dx, y + dy);
+                    let inside =
+                        (1..=self.width).contains(&next.0) && (1..=self.height).contains(&next.1);
+                    if !(inside || next == from || next == to)
+                        || self.blizzards[(t + 1) % period].contains(&next)
+                    {
+                        continue;
+                    }
+                    if seen.insert((next, (t + 1) % period)) {
+                        queue.push_back((next, t + 1));
+                    }
+                }
+            }
+
+            None
+        }
+    }
+
+    const INPUT: &str = include_str!("../../../examples/2022/day24/example.txt");
+
+    #[test]
+    fn observed() {
+        let field = Day24::load(INPUT).unwrap();
+
+        // waiting at the start is always possible
+        let mut waiting = |_, e: &Expedition| {
+            assert!(!e.positions.is_empty());
+            std::ops::ControlFlow::Continue(())
+        };
+        assert_eq!(traverse(&field, 1, &mut waiting), Ok(18));
+
+        let mut first = String::new();
+        let mut drawn = |round, e: &Expedition| {
+            if round == 1 {
+                first = e.to_string();
+            }
+            std::ops::ControlFlow::Continue(())
+        };
+        traverse(&field, 1, &mut drawn).unwrap();
+        assert_eq!(
+            first,
+            "#E######\n#E>3.<.#\n#<..<<.#\n#>2.22.#\n#>v..^<#\n######.#"
+        );
+
+        let err = traverse(&field, 3, &mut common::StepLimit(20)).unwrap_err();
+        assert_eq!(err.to_string(), "day 24: stopped after step 20");
+    }
+
+    #[test]
+    fn no_way() {
+        // the row of blizzards below the start never lets the expedition in
+        let err = puzzle_1("#.####\n#>>>>#\n#<<<<#\n####.#").unwrap_err();
+        assert_eq!(
+            err,
+            Error::solve(Day24::DAY, "there is no way through the valley")
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let err = puzzle_1(&INPUT.replace("#<^v^^>#", "#<^v^^<>#")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 24, line 5, column 9: expected 8 columns, found \"#\""
+        );
+
+        let err = puzzle_2(&INPUT.replace(">>.<^<", ">>.<%<")).unwrap_err();
+        assert_eq!(err.location(), Some((2, 6)));
+
+        let err = puzzle_1("#.###\n<...#\n#...#\n###.#").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 24, line 2, column 1: expected ground or a wall, found \"<...#\""
+        );
+    }
+
+    #[test]
+    fn assumptions() {
+        let field = Day24::load(INPUT).unwrap();
+        assert!(Day24::assumptions(&field, &()).iter().all(|a| a.holds()));
+
+        let moved = INPUT.replacen("#.######", "###.####", 1);
+        let checked = Day24::assumptions(&Day24::load(&moved).unwrap(), &());
+        assert_eq!(
+            checked[0].result,
+            Err("the top row is open at x = 3".to_string())
+        );
+        assert!(checked[1].holds());
+
+        let walled = INPUT.replace("#>v.><>#", "#>v#><>.");
+        let checked = Day24::assumptions(&Day24::load(&walled).unwrap(), &());
+        assert_eq!(
+            checked[2].result,
+            Err("(7, 3) isn't a wall, (3, 3) is a wall".to_string())
+        );
+
+        let blowing = INPUT.replace("#>>.<^<#", "#^>.<^<#");
+        let checked = Day24::assumptions(&Day24::load(&blowing).unwrap(), &());
+        assert_eq!(
+            checked[3].result,
+            Err("the blizzard at (1, 1) blows North".to_string())
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn brute_forced(input in generate::valley()) {
+            let valley = Valley::new(&input);
+            let (start, goal) = ((1, 0), (valley.width, valley.height + 1));
+
+            let there = valley.cross(start, goal, 0);
+            let back = there.and_then(|t| valley.cross(goal, start, t));
+            let again = back.and_then(|t| valley.cross(start, goal, t));
+            let no_way = Error::solve(Day24::DAY, "there is no way through the valley");
+
+            prop_assert_eq!(puzzle_1(&input), there.map(Answer::from).ok_or(no_way.clone()));
+            prop_assert_eq!(puzzle_2(&input), again.map(Answer::from).ok_or(no_way));
+        }
+    }
+}
@@ -1,19 +1,25 @@
 use std::fmt::Display;
 
-struct SNAFUNumber {
+use common::{Answer, Error, Result, Solution};
+
+pub struct SNAFUNumber {
     digits: Vec<char>,
 }
 
 impl SNAFUNumber {
-    pub fn new(digits: Vec<char>) -> Self {
-        let valid = vec!['0', '1', '2', '-', '='];
-        for c in digits.iter() {
-            if !valid.contains(c) {
-                panic!("invalid snafu digit: {}", c);
-            }
+    /// Parses a line of the input, the digits are stored starting with the lowest one.
+    pub fn parse(input: &str, line: &str) -> Result<Self> {
+        let valid = ['0', '1', '2', '-', '='];
+        if line.is_empty() {
+            return Err(Error::parse(Day25::DAY, input, line, "a SNAFU number"));
+        }
+        if let Some(i) = line.find(|c| !valid.contains(&c)) {
+            return Err(Error::parse(Day25::DAY, input, &line[i..], "a SNAFU digit"));
         }
 
-        SNAFUNumber { digits }
+        Ok(SNAFUNumber {
+            digits: line.chars().rev().collect(),
+        })
     }
 
     pub fn decimal(&self) -> i64 {
@@ -26,9 +32,9 @@ impl SNAFUNumber {
                     '0' => 0,
                     '1' => factor,
                     '2' => 2 * factor,
-                    '-' => -1 * factor,
+                    '-' => -factor,
                     '=' => -2 * factor,
-                    _ => panic!("invalid digit for conversion: {}", c),
+                    _ => unreachable!("digits are checked by the parser"),
                 }
             })
             .sum()
@@ -48,7 +54,7 @@ impl Display for SNAFUNumber {
 impl From<i64> for SNAFUNumber {
     fn from(value: i64) -> Self {
         if value == 0 {
-            return SNAFUNumber::new(vec!['0']);
+            return SNAFUNumber { digits: vec!['0'] };
         }
 
         let mut number = value;
@@ -83,50 +89,51 @@ impl From<i64> for SNAFUNumber {
             pos += 1;
         }
 
-        SNAFUNumber::new(digits)
+        SNAFUNumber { digits }
     }
 }
 
-impl From<&str> for SNAFUNumber {
-    fn from(value: &str) -> Self {
-        SNAFUNumber::new(value.chars().rev().collect::<Vec<_>>())
-    }
+fn parse_input(input: &str) -> Result<Vec<SNAFUNumber>> {
+    input
+        .lines()
+        .map(|l| SNAFUNumber::parse(input, l))
+        .collect()
 }
 
-fn parse_input(input: &str) -> Vec<SNAFUNumber> {
-    input.lines().map(|l| l.into()).collect()
-}
+pub struct Day25;
 
-pub fn puzzle_1(input: &str) -> String {
-    let numbers = parse_input(input);
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const PARTS: u8 = 1;
 
-    let result = SNAFUNumber::from(numbers.iter().map(|n| n.decimal()).sum::<i64>());
+    type Input = Vec<SNAFUNumber>;
+    type Params = ();
 
-    result.to_string()
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(numbers: &Self::Input, _: &()) -> Result<Answer> {
+        let result = SNAFUNumber::from(numbers.iter().map(|n| n.decimal()).sum::<i64>());
+
+        Ok(Answer::Text(result.to_string()))
+    }
+}
+
+pub fn puzzle_1(input: &str) -> Result<Answer> {
+    Day25::part1(&Day25::load(input)?, &())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "\
-1=-0-2
-12111
-2=0=
-21
-2=01
-111
-20012
-112
-1=-1=
-1-12
-12
-1=
-122";
-
     #[test]
-    fn p1() {
-        let result = puzzle_1(INPUT);
-        assert_eq!(result, "2=-1=0");
+    fn malformed() {
+        let err = puzzle_1("1=-0-2\n12111\n2=0=3").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 25, line 3, column 5: expected a SNAFU digit, found \"3\""
+        );
     }
 }
@@ -0,0 +1,160 @@
+use std::{
+    collections::BTreeMap,
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::Path,
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+use common::Answer;
+use toml::Table;
+
+use crate::{config, default_input, diagnostic, read_input, registry::Puzzle};
+
+/// Answers of a single day, errors are already rendered for printing.
+pub struct Outcome {
+    pub year: u16,
+    pub day: u8,
+    pub parts: Vec<PartOutcome>,
+    pub elapsed: Duration,
+}
+
+pub struct PartOutcome {
+    pub part: u8,
+    pub answer: Result<Answer, String>,
+    pub elapsed: Duration,
+}
+
+impl Outcome {
+    /// Every part fails with the same error, e.g. if the input can't be parsed.
+    fn failed(year: u16, day: u8, parts: &[u8], error: String, elapsed: Duration) -> Self {
+        Outcome {
+            year,
+            day,
+            parts: parts
+                .iter()
+                .map(|&part| PartOutcome {
+                    part,
+                    answer: Err(error.clone()),
+                    elapsed: Duration::ZERO,
+                })
+                .collect(),
+            elapsed,
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.parts.iter().all(|p| p.answer.is_ok())
+    }
+}
+
+/// Reads the input and its parameters, `overrides` are `key=value` pairs replacing them.
+///
+/// A failed parse fails every part.
+pub fn solve(
+    year: u16,
+    puzzle: &dyn Puzzle,
+    parts: &[u8],
+    path: &Path,
+    overrides: &[String],
+) -> Outcome {
+    let start = Instant::now();
+
+    let loaded = read_input(path).and_then(|input| {
+        let mut params = config::load(path)?;
+        config::apply(&mut params, overrides)?;
+        Ok((input, params))
+    });
+    let (input, params) = match loaded {
+        Ok(loaded) => loaded,
+        Err(e) => return Outcome::failed(year, puzzle.day(), parts, e, start.elapsed()),
+    };
+
+    // the time includes reading the input
+    let outcome = solve_input(year, puzzle, parts, path, &input, &params);
+    Outcome {
+        elapsed: start.elapsed(),
+        ..outcome
+    }
+}
+
+/// Solves an input that is already read, `path` is only shown in diagnostics.
+pub fn solve_input(
+    year: u16,
+    puzzle: &dyn Puzzle,
+    parts: &[u8],
+    path: &Path,
+    input: &str,
+    params: &Table,
+) -> Outcome {
+    let start = Instant::now();
+
+    match puzzle.run(input, parts, params) {
+        Ok(results) => Outcome {
+            year,
+            day: puzzle.day(),
+            parts: results
+                .into_iter()
+                .map(|r| PartOutcome {
+                    part: r.part,
+                    answer: r.answer.map_err(|e| diagnostic(&e, path, input)),
+                    elapsed: r.elapsed,
+                })
+                .collect(),
+            elapsed: start.elapsed(),
+        },
+        Err(e) => {
+            let error = diagnostic(&e, path, input);
+            Outcome::failed(year, puzzle.day(), parts, error, start.elapsed())
+        }
+    }
+}
+
+/// Solves all parts of the days of a year on `jobs` threads, 0 uses one per core.
+///
+/// `done` is called in day order, as soon as all earlier days are finished.
+pub fn solve_all(
+    year: u16,
+    puzzles: &[&'static dyn Puzzle],
+    jobs: usize,
+    mut done: impl FnMut(&Outcome),
+) -> Result<Vec<Outcome>, String> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(|e| format!("could not start the thread pool: {}", e))?;
+
+    let (sender, receiver) = mpsc::channel();
+    for (i, &puzzle) in puzzles.iter().enumerate() {
+        let sender = sender.clone();
+        pool.spawn(move || {
+            let parts = (1..=puzzle.parts()).collect::<Vec<_>>();
+            let path = default_input(year, puzzle.day());
+
+            // a panicking day shouldn't hold back the ones after it
+            let outcome =
+                catch_unwind(AssertUnwindSafe(|| solve(year, puzzle, &parts, &path, &[])))
+                    .unwrap_or_else(|_| {
+                        let error = "panicked".to_string();
+                        Outcome::failed(year, puzzle.day(), &parts, error, Duration::ZERO)
+                    });
+
+            // the receiver lives until every day is done
+            sender.send((i, outcome)).ok();
+        });
+    }
+    drop(sender);
+
+    let mut pending = BTreeMap::new();
+    let mut outcomes = Vec::with_capacity(puzzles.len());
+    for (i, outcome) in receiver {
+        pending.insert(i, outcome);
+
+        while let Some(outcome) = pending.remove(&outcomes.len()) {
+            done(&outcome);
+            outcomes.push(outcome);
+        }
+    }
+
+    Ok(outcomes)
+}
@@ -0,0 +1,426 @@
+use std::{
+    fmt::Display,
+    io::{stdout, Write},
+    ops::ControlFlow,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use common::{Observer, Solution};
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    terminal,
+};
+use toml::Table;
+
+use crate::{diagnostic, registry::read_params};
+
+/// The drawing of a state after `step`.
+pub struct Frame {
+    pub step: usize,
+    pub text: String,
+}
+
+/// Draws every `every`th step of a simulation, until there are `limit` frames.
+pub struct Recorder {
+    every: usize,
+    limit: usize,
+    frames: Vec<Frame>,
+}
+
+impl Recorder {
+    pub fn new(every: usize, limit: usize) -> Self {
+        Recorder {
+            every: every.max(1),
+            limit,
+            frames: Vec::new(),
+        }
+    }
+}
+
+impl<S: Display> Observer<S> for Recorder {
+    fn step(&mut self, step: usize, state: &S) -> ControlFlow<()> {
+        if step.is_multiple_of(self.every) {
+            self.frames.push(Frame {
+                step,
+                text: state.to_string(),
+            });
+        }
+
+        match self.frames.len() >= self.limit {
+            true => ControlFlow::Break(()),
+            false => ControlFlow::Continue(()),
+        }
+    }
+}
+
+// the simulation behind a part, `None` for days without one
+fn simulate(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    params: &Table,
+    recorder: &mut Recorder,
+) -> Option<common::Result<()>> {
+    use y2022_day09::Day09;
+    use y2022_day14::Day14;
+    use y2022_day17::Day17;
+    use y2022_day22::Day22;
+    use y2022_day23::Day23;
+    use y2022_day24::Day24;
+
+    let first = part == 1;
+    let result = match (year, day) {
+        (2022, 9) => Day09::load(input)
+            .and_then(|moves| y2022_day09::simulate(&moves, if first { 2 } else { 10 }, recorder))
+            .map(drop),
+        (2022, 14) => Day14::load(input)
+            .and_then(|cave| y2022_day14::pour(&cave, !first, recorder))
+            .map(drop),
+        (2022, 17) => Day17::load(input)
+            .and_then(|jets| {
+                let count = if first { 2022 } else { jets.len() * 20 };
+                y2022_day17::drop_stones(&jets, count, recorder)
+            })
+            .map(drop),
+        (2022, 22) => Day22::load(input)
+            .and_then(|(map, moves)| match first {
+                true => y2022_day22::walk_flat(&map, &moves, recorder),
+                false => {
+                    let params = read_params::<Day22>(params)?;
+                    y2022_day22::walk_cube(&map, &moves, &params, recorder)
+                }
+            })
+            .map(drop),
+        (2022, 23) => Day23::load(input)
+            .and_then(|elves| y2022_day23::spread(&elves, first.then_some(10), recorder))
+            .map(drop),
+        (2022, 24) => Day24::load(input)
+            .and_then(|field| y2022_day24::traverse(&field, if first { 1 } else { 3 }, recorder))
+            .map(drop),
+        _ => return None,
+    };
+
+    Some(result)
+}
+
+/// Runs a part with an animation and draws its frames, the text tells how the
+/// simulation ended. Fails if the input can't be simulated at all.
+pub fn record(
+    year: u16,
+    day: u8,
+    part: u8,
+    path: &Path,
+    input: &str,
+    params: &Table,
+    mut recorder: Recorder,
+) -> Result<(Vec<Frame>, String), String> {
+    if !(1..=2).contains(&part) {
+        return Err(format!("day {} has no part {}", day, part));
+    }
+
+    let result = simulate(year, day, part, input, params, &mut recorder)
+        .ok_or_else(|| format!("day {} of {} has no animation", day, year))?;
+
+    let frames = recorder.frames;
+    let end = match result {
+        Ok(()) => "finished".to_string(),
+        Err(_) if frames.len() >= recorder.limit => {
+            format!("stopped after {} frames", recorder.limit)
+        }
+        Err(e) if frames.is_empty() => return Err(diagnostic(&e, path, input)),
+        Err(e) => e.to_string(),
+    };
+    if frames.is_empty() {
+        return Err(format!("day {} {} before the first frame", day, end));
+    }
+
+    Ok((frames, end))
+}
+
+/// Which frame is shown and how fast they change.
+struct Player {
+    frames: Vec<Frame>,
+    end: String,
+    index: usize,
+    fps: f64,
+    paused: bool,
+    /// The digits typed after `g`, while a frame to jump to is entered.
+    jump: Option<String>,
+}
+
+const HELP: &str = "space pause, ←/→ step, +/- speed, g go to frame, q quit";
+
+impl Player {
+    fn new(frames: Vec<Frame>, end: String, fps: f64) -> Self {
+        Player {
+            frames,
+            end,
+            index: 0,
+            fps,
+            paused: false,
+            jump: None,
+        }
+    }
+
+    fn delay(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+
+    /// Moves on to the next frame, the last one stays on screen.
+    fn tick(&mut self) {
+        if self.index + 1 < self.frames.len() {
+            self.index += 1;
+        } else {
+            self.paused = true;
+        }
+    }
+
+    fn step(&mut self, by: isize) {
+        self.paused = true;
+        self.index = self
+            .index
+            .saturating_add_signed(by)
+            .min(self.frames.len() - 1);
+    }
+
+    /// Reacts to a key, `false` once the player should close.
+    fn key(&mut self, key: KeyEvent) -> bool {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return false;
+        }
+
+        if let Some(digits) = self.jump.as_mut() {
+            match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() => digits.push(c),
+                KeyCode::Backspace => {
+                    digits.pop();
+                }
+                KeyCode::Enter => {
+                    // frames are counted from 1 on screen
+                    if let Ok(frame) = digits.parse::<usize>() {
+                        self.paused = true;
+                        self.index = frame.clamp(1, self.frames.len()) - 1;
+                    }
+                    self.jump = None;
+                }
+                KeyCode::Esc => self.jump = None,
+                _ => {}
+            }
+            return true;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => {
+                // playing from the last frame starts over
+                if self.paused && self.index + 1 == self.frames.len() {
+                    self.index = 0;
+                }
+                self.paused = !self.paused;
+            }
+            KeyCode::Right | KeyCode::Char('l') => self.step(1),
+            KeyCode::Left | KeyCode::Char('h') => self.step(-1),
+            KeyCode::Home => self.step(isize::MIN),
+            KeyCode::End => self.step(isize::MAX),
+            KeyCode::Char('+' | '=') => self.fps = (self.fps * 2.0).min(240.0),
+            KeyCode::Char('-') => self.fps = (self.fps / 2.0).max(0.25),
+            KeyCode::Char('g') => self.jump = Some(String::new()),
+            _ => {}
+        }
+        true
+    }
+
+    /// The whole screen, cut to `rows` and `columns`, with a status line at the bottom.
+    fn screen(&self, rows: usize, columns: usize) -> String {
+        let frame = &self.frames[self.index];
+
+        // move home, every line clears what is left of the previous frame
+        let mut screen = "\x1b[H".to_string();
+        for line in frame.text.lines().take(rows.saturating_sub(1)) {
+            screen.extend(line.chars().take(columns));
+            screen.push_str("\x1b[K\r\n");
+        }
+        screen.push_str("\x1b[J");
+
+        let status = match &self.jump {
+            Some(digits) => format!("go to frame: {}", digits),
+            None => {
+                let state = match self.paused {
+                    true if self.index + 1 == self.frames.len() => self.end.as_str(),
+                    true => "paused",
+                    false => "playing",
+                };
+                format!(
+                    "frame {}/{}, step {}, {} fps, {} | {}",
+                    self.index + 1,
+                    self.frames.len(),
+                    frame.step,
+                    self.fps,
+                    state,
+                    HELP
+                )
+            }
+        };
+        screen.extend(status.chars().take(columns));
+        screen.push_str("\x1b[K");
+
+        screen
+    }
+}
+
+/// Leaves the alternate screen and raw mode again, even after an error.
+struct Terminal;
+
+impl Terminal {
+    fn enter() -> Result<Self, String> {
+        terminal::enable_raw_mode()
+            .map_err(|e| format!("could not control the terminal: {}", e))?;
+        // alternate screen and a hidden cursor
+        print!("\x1b[?1049h\x1b[?25l");
+        Ok(Terminal)
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\x1b[?1049l");
+        stdout().flush().ok();
+        terminal::disable_raw_mode().ok();
+    }
+}
+
+/// Shows the frames until the player is closed.
+pub fn play(frames: Vec<Frame>, end: String, fps: f64) -> Result<(), String> {
+    let failed = |e: std::io::Error| format!("could not draw the animation: {}", e);
+    let mut player = Player::new(frames, end, fps.clamp(0.25, 240.0));
+
+    let _terminal = Terminal::enter()?;
+    let mut next = Instant::now() + player.delay();
+    loop {
+        let (columns, rows) = terminal::size().map_err(failed)?;
+        print!("{}", player.screen(rows as usize, columns as usize));
+        stdout().flush().map_err(failed)?;
+
+        // a paused player only changes on a key
+        let waiting = player.paused || player.jump.is_some();
+        let wait = next.saturating_duration_since(Instant::now());
+        if !waiting && !event::poll(wait).map_err(failed)? {
+            player.tick();
+            next = Instant::now() + player.delay();
+            continue;
+        }
+
+        if let Event::Key(key) = event::read().map_err(failed)? {
+            if key.kind == KeyEventKind::Press && !player.key(key) {
+                return Ok(());
+            }
+            next = Instant::now() + player.delay();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(player: &mut Player, keys: &[KeyCode]) {
+        for &code in keys {
+            assert!(player.key(KeyEvent::from(code)));
+        }
+    }
+
+    #[test]
+    fn recorded() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let path = root.join("examples/2022/day14/example.txt");
+        let input = crate::read_input(&path).unwrap();
+
+        let (frames, end) = record(
+            2022,
+            14,
+            1,
+            &path,
+            &input,
+            &Table::new(),
+            Recorder::new(5, 100),
+        )
+        .unwrap();
+        assert_eq!(end, "finished");
+        assert_eq!(
+            frames.iter().map(|f| f.step).collect::<Vec<_>>(),
+            [5, 10, 15, 20]
+        );
+
+        let (frames, end) = record(
+            2022,
+            14,
+            2,
+            &path,
+            &input,
+            &Table::new(),
+            Recorder::new(1, 3),
+        )
+        .unwrap();
+        assert_eq!(end, "stopped after 3 frames");
+        assert_eq!(frames.len(), 3);
+
+        assert_eq!(
+            record(
+                2022,
+                1,
+                1,
+                &path,
+                &input,
+                &Table::new(),
+                Recorder::new(1, 3)
+            )
+            .err(),
+            Some("day 1 of 2022 has no animation".to_string())
+        );
+
+        // the cube of the example is folded by its parameters
+        let path = root.join("examples/2022/day22/example.txt");
+        let input = crate::read_input(&path).unwrap();
+        let params = crate::config::load(&path).unwrap();
+        let (frames, end) =
+            record(2022, 22, 2, &path, &input, &params, Recorder::new(1, 100)).unwrap();
+        assert_eq!(end, "finished");
+        assert_eq!(frames.len(), 13);
+        assert!(frames[12].text.starts_with("        >>v#"));
+    }
+
+    #[test]
+    fn controls() {
+        let frames = (1..=20)
+            .map(|step| Frame {
+                step,
+                text: format!("{}\nsecond line", step),
+            })
+            .collect();
+        let mut player = Player::new(frames, "finished".to_string(), 10.0);
+
+        player.tick();
+        press(
+            &mut player,
+            &[KeyCode::Right, KeyCode::Right, KeyCode::Left],
+        );
+        assert_eq!((player.index, player.paused), (2, true));
+
+        press(&mut player, &[KeyCode::Char('g'), KeyCode::Char('1')]);
+        press(&mut player, &[KeyCode::Char('5'), KeyCode::Enter]);
+        assert_eq!(player.index, 14);
+
+        press(&mut player, &[KeyCode::End, KeyCode::Char('+')]);
+        assert_eq!(player.index, 19);
+        assert_eq!(
+            player.screen(2, 30),
+            "\x1b[H20\x1b[K\r\n\x1b[Jframe 20/20, step 20, 20 fps, \x1b[K"
+        );
+
+        press(&mut player, &[KeyCode::Char(' ')]);
+        assert_eq!((player.index, player.paused), (0, false));
+        assert!(!player.key(KeyEvent::from(KeyCode::Char('q'))));
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+/// Recorded answers of the real inputs, one `[YYYY.dayNN]` table per day.
+#[derive(Default, Deserialize)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, DayAnswers>>);
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        let answers = self
+            .0
+            .get(&year.to_string())?
+            .get(&format!("day{:02}", day))?;
+        let answer = match part {
+            1 => answers.part1.as_deref(),
+            2 => answers.part2.as_deref(),
+            _ => None,
+        };
+
+        // multi-line strings in toml usually end with a newline
+        answer.map(|a| a.strip_suffix('\n').unwrap_or(a))
+    }
+}
+
+/// Line by line comparison, unchanged lines are indented, others marked with - and +.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+
+    let mut out = Vec::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => out.push(format!("  {}", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    out.push(format!("- {}", e));
+                }
+                if let Some(a) = a {
+                    out.push(format!("+ {}", a));
+                }
+            }
+        }
+    }
+
+    out.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[2022.day01]
+part1 = "24000"
+part2 = "45000"
+
+[2022.day10]
+part2 = """
+##..
+..##
+"""
+"#;
+
+    #[test]
+    fn get() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.get(2022, 1, 1), Some("24000"));
+        assert_eq!(answers.get(2022, 10, 1), None);
+        assert_eq!(answers.get(2022, 10, 2), Some("##..\n..##"));
+        assert_eq!(answers.get(2022, 2, 1), None);
+        assert_eq!(answers.get(2021, 1, 1), None);
+    }
+
+    #[test]
+    fn unknown_field() {
+        assert!(Answers::parse("[2022.day01]\npart3 = \"1\"").is_err());
+    }
+
+    #[test]
+    fn multi_line_diff() {
+        let result = diff("##..\n..##\n####", "##..\n.###\n####");
+
+        assert_eq!(result, "  ##..\n- ..##\n+ .###\n  ####");
+    }
+}
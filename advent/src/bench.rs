@@ -0,0 +1,156 @@
+use std::time::Duration;
+
+use serde::Serialize;
+use toml::Table;
+
+use crate::registry::{Puzzle, Timing};
+
+/// Statistics of repeated measurements, all values in nanoseconds.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+    pub stddev: u64,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut ns = samples
+            .iter()
+            .map(|d| d.as_nanos() as u64)
+            .collect::<Vec<_>>();
+        ns.sort_unstable();
+
+        if ns.is_empty() {
+            return Stats {
+                min: 0,
+                median: 0,
+                mean: 0,
+                stddev: 0,
+            };
+        }
+
+        let n = ns.len();
+        let median = if n % 2 == 0 {
+            (ns[n / 2 - 1] + ns[n / 2]) / 2
+        } else {
+            ns[n / 2]
+        };
+
+        let mean = ns.iter().map(|&v| v as f64).sum::<f64>() / n as f64;
+        // sample standard deviation, a single run has none
+        let variance = if n > 1 {
+            ns.iter().map(|&v| (v as f64 - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Stats {
+            min: ns[0],
+            median,
+            mean: mean.round() as u64,
+            stddev: variance.sqrt().round() as u64,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse: Stats,
+    pub parts: Vec<Stats>,
+}
+
+#[derive(Serialize)]
+pub struct Report {
+    pub year: u16,
+    pub unit: &'static str,
+    pub iterations: usize,
+    pub days: Vec<DayReport>,
+}
+
+pub fn bench_day(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    params: &Table,
+    iterations: usize,
+) -> common::Result<DayReport> {
+    let timings = (0..iterations)
+        .map(|_| puzzle.time(input, params))
+        .collect::<common::Result<Vec<Timing>>>()?;
+
+    let parse = timings.iter().map(|t| t.parse).collect::<Vec<_>>();
+    let parts = (0..puzzle.parts() as usize)
+        .map(|p| {
+            let samples = timings.iter().map(|t| t.parts[p]).collect::<Vec<_>>();
+            Stats::new(&samples)
+        })
+        .collect();
+
+    Ok(DayReport {
+        day: puzzle.day(),
+        parse: Stats::new(&parse),
+        parts,
+    })
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+pub fn print_day(report: &DayReport) {
+    println!("Day {:02}", report.day);
+    println!(
+        "  {:<8} {:>12} {:>12} {:>12} {:>12}",
+        "", "min", "median", "mean", "stddev"
+    );
+
+    let phases = std::iter::once(("parse".to_string(), &report.parse)).chain(
+        report
+            .parts
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (format!("part {}", i + 1), s)),
+    );
+
+    for (name, s) in phases {
+        println!(
+            "  {:<8} {:>12} {:>12} {:>12} {:>12}",
+            name,
+            format_ns(s.min),
+            format_ns(s.median),
+            format_ns(s.mean),
+            format_ns(s.stddev)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = [5, 1, 4, 2].map(Duration::from_nanos);
+        let stats = Stats::new(&samples);
+
+        assert_eq!(
+            stats,
+            Stats {
+                min: 1,
+                median: 3,
+                mean: 3,
+                stddev: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = Stats::new(&[Duration::from_nanos(7)]);
+
+        assert_eq!(stats.median, 7);
+        assert_eq!(stats.stddev, 0);
+    }
+}
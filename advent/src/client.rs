@@ -0,0 +1,166 @@
+use std::{
+    fs::{create_dir_all, write},
+    path::Path,
+    time::Duration,
+};
+
+use ureq::{http::Response, Agent, Body};
+
+/// Environment variable with the value of the `session` cookie of a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Talks to the puzzle website, or anything serving the same paths below `base_url`.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .http_status_as_error(false)
+            .user_agent(concat!("advent/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .into();
+
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Reads the session token from [`SESSION_VAR`].
+    pub fn from_env(base_url: &str) -> Result<Self, String> {
+        match std::env::var(SESSION_VAR) {
+            Ok(session) if !session.trim().is_empty() => Ok(Client::new(base_url, &session)),
+            _ => Err(format!(
+                "{} must hold the session cookie of the website",
+                SESSION_VAR
+            )),
+        }
+    }
+
+    /// Downloads the personal puzzle input of a day.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+
+        let response = self.agent.get(&url).header("Cookie", self.cookie()).call();
+
+        read(&url, response, day, year)
+    }
+
+    /// Posts the answer of a part, the page of the response tells if it was right.
+    pub fn answer(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", self.cookie())
+            .send_form([("level", level.as_str()), ("answer", answer)]);
+
+        read(&url, response, day, year)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn read(
+    url: &str,
+    response: Result<Response<Body>, ureq::Error>,
+    day: u8,
+    year: u16,
+) -> Result<String, String> {
+    let mut response = response.map_err(|e| format!("request to {} failed: {}", url, e))?;
+
+    let status = response.status().as_u16();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| format!("request to {} failed: {}", url, e))?;
+
+    match status {
+        200 => Ok(body),
+        // the website answers with 400 for a session that isn't logged in
+        400 | 401 => Err(format!("{} was rejected, is {} expired?", url, SESSION_VAR)),
+        404 => Err(format!("day {} of {} isn't unlocked yet", day, year)),
+        _ => Err(format!(
+            "{} answered with {}: {}",
+            url,
+            status,
+            body.lines().next().unwrap_or("")
+        )),
+    }
+}
+
+/// Downloads the input of a day to `path`, an existing file is never downloaded again.
+pub fn fetch_input(client: &Client, year: u16, day: u8, path: &Path) -> Result<(), String> {
+    if path.exists() {
+        return Err(format!(
+            "{} exists already, delete it to download the input again",
+            path.display()
+        ));
+    }
+
+    let input = client.input(year, day)?;
+
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir).map_err(|e| format!("could not create {}: {}", dir.display(), e))?;
+    }
+    write(path, input).map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{read_to_string, remove_dir_all};
+
+    use super::*;
+    use crate::stub::Stub;
+
+    #[test]
+    fn fetch_once() {
+        let stub = Stub::serve(vec![(200, "1000\n2000\n")]);
+        let client = Client::new(&stub.url, "c0ffee");
+        let dir = std::env::temp_dir().join(format!("advent-fetch-{}", std::process::id()));
+        let path = dir.join("inputs/2022/day01.txt");
+
+        fetch_input(&client, 2022, 1, &path).unwrap();
+
+        let request = stub.request();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(request
+            .to_lowercase()
+            .contains("\r\ncookie: session=c0ffee\r\n"));
+        assert_eq!(read_to_string(&path).unwrap(), "1000\n2000\n");
+
+        // the cached copy is kept and nothing is requested
+        let err = fetch_input(&client, 2022, 1, &path).unwrap_err();
+        assert!(err.ends_with("exists already, delete it to download the input again"));
+        assert_eq!(stub.answered(), 0);
+
+        remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rejected() {
+        let stub = Stub::serve(vec![
+            (404, "Not Found"),
+            (400, "Puzzle inputs differ by user."),
+        ]);
+        let client = Client::new(&stub.url, "c0ffee");
+
+        assert_eq!(
+            client.input(2022, 26),
+            Err("day 26 of 2022 isn't unlocked yet".to_string())
+        );
+        assert!(client.input(2022, 1).unwrap_err().contains("AOC_SESSION"));
+    }
+}
@@ -0,0 +1,80 @@
+use std::{
+    fs::read_to_string,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use toml::{Table, Value};
+
+use crate::examples;
+
+/// Parameter file of an input, `inputs/2022/day15.txt` is configured by `inputs/2022/day15.toml`.
+pub fn path(input: &Path) -> PathBuf {
+    input.with_extension("toml")
+}
+
+/// Reads the parameters of an input, without a file the solutions use their defaults.
+/// Examples keep theirs with the answers instead.
+pub fn load(input: &Path) -> Result<Table, String> {
+    if examples::is_example(input) {
+        return examples::params(input);
+    }
+
+    let path = path(input);
+
+    match read_to_string(&path) {
+        Ok(text) => text
+            .parse::<Table>()
+            .map_err(|e| format!("invalid {}: {}", path.display(), e)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(Table::new()),
+        Err(e) => Err(format!("could not read {}: {}", path.display(), e)),
+    }
+}
+
+/// Sets `key=value` overrides, values are TOML and anything else is taken as a string.
+pub fn apply(params: &mut Table, overrides: &[String]) -> Result<(), String> {
+    for o in overrides {
+        let (key, value) = o
+            .split_once('=')
+            .ok_or_else(|| format!("expected key=value, found {:?}", o))?;
+
+        let value = format!("value = {}", value)
+            .parse::<Table>()
+            .ok()
+            .and_then(|mut t| t.remove("value"))
+            .unwrap_or_else(|| Value::String(value.to_string()));
+
+        params.insert(key.trim().to_string(), value);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides() {
+        let mut params = "row = 2000000\nbounds = [0, 4000000]"
+            .parse::<Table>()
+            .unwrap();
+        let overrides = ["row=10", "bounds = [0, 20]", "name=example"].map(String::from);
+
+        apply(&mut params, &overrides).unwrap();
+
+        assert_eq!(params["row"], Value::Integer(10));
+        assert_eq!(params["bounds"], Value::Array(vec![0.into(), 20.into()]));
+        assert_eq!(params["name"], Value::String("example".to_string()));
+        assert!(apply(&mut params, &["row".to_string()]).is_err());
+    }
+
+    #[test]
+    fn next_to_input() {
+        assert_eq!(
+            path(Path::new("inputs/2022/day15.txt")),
+            PathBuf::from("inputs/2022/day15.toml")
+        );
+        assert_eq!(load(Path::new("no/such/input.txt")), Ok(Table::new()));
+    }
+}
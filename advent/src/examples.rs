@@ -0,0 +1,188 @@
+use std::{
+    collections::BTreeMap,
+    fs::{read_dir, read_to_string},
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use toml::Table;
+
+use crate::{answers::diff, diagnostic, read_input, registry::Puzzle};
+
+/// Examples of a day, `examples/2022/day15/example.txt` with the answers in `example.toml`.
+pub fn dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(format!("examples/{}/day{:02}", year, day))
+}
+
+/// Answers of an example and the parameters needed to get them.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+    #[serde(default)]
+    params: Table,
+}
+
+pub struct Example {
+    pub path: PathBuf,
+    input: String,
+    params: Table,
+    answers: BTreeMap<u8, String>,
+}
+
+// the answers next to an example input
+fn expected(input: &Path) -> Result<Expected, String> {
+    let toml = input.with_extension("toml");
+    let text =
+        read_to_string(&toml).map_err(|e| format!("could not read {}: {}", toml.display(), e))?;
+    toml::from_str::<Expected>(&text).map_err(|e| format!("invalid {}: {}", toml.display(), e))
+}
+
+/// Whether an input is one of the examples of the workspace, their parameters are kept
+/// with the answers.
+pub fn is_example(input: &Path) -> bool {
+    // other directories named examples keep their parameters next to the inputs
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent();
+    let root = workspace.map(|w| w.join("examples"));
+
+    match (input.canonicalize(), root.map(|r| r.canonicalize())) {
+        (Ok(input), Some(Ok(root))) => input.starts_with(root),
+        _ => false,
+    }
+}
+
+/// Parameters an example needs to get its answers.
+pub fn params(input: &Path) -> Result<Table, String> {
+    expected(input).map(|e| e.params)
+}
+
+/// Reads the examples of a day in file name order, a missing directory has none.
+pub fn load(dir: &Path) -> Result<Vec<Example>, String> {
+    let entries = match read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("could not read {}: {}", dir.display(), e)),
+    };
+
+    let mut paths = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|e| e == "txt"))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let expected = expected(&path)?;
+
+            // multi-line strings in toml usually end with a newline
+            let answers = [(1, expected.part1), (2, expected.part2)]
+                .into_iter()
+                .filter_map(|(part, a)| Some((part, a?.trim_end_matches('\n').to_string())))
+                .collect();
+
+            Ok(Example {
+                input: read_input(&path)?,
+                path,
+                params: expected.params,
+                answers,
+            })
+        })
+        .collect()
+}
+
+/// Solves the parts of an example which have an answer, `Err` describes a mismatch.
+pub fn check(puzzle: &dyn Puzzle, example: &Example) -> Vec<(u8, Result<(), String>)> {
+    let parts = example.answers.keys().copied().collect::<Vec<_>>();
+    let render = |e| diagnostic(&e, &example.path, &example.input);
+
+    let results = match puzzle.run(&example.input, &parts, &example.params) {
+        Ok(results) => results,
+        Err(e) => {
+            let error = render(e);
+            return parts.into_iter().map(|p| (p, Err(error.clone()))).collect();
+        }
+    };
+
+    results
+        .into_iter()
+        .map(|r| {
+            let expected = &example.answers[&r.part];
+            let result = match r.answer {
+                Ok(answer) if answer.to_string() == *expected => Ok(()),
+                Ok(answer) => Err(diff(expected, &answer.to_string())),
+                Err(e) => Err(render(e)),
+            };
+            (r.part, result)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use super::*;
+    use crate::registry::YEARS;
+
+    #[test]
+    fn every_example() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let days = YEARS
+            .iter()
+            .flat_map(|y| y.days.iter().map(move |&d| (y.year, d)))
+            .collect::<Vec<_>>();
+
+        // the slow examples shouldn't wait for each other
+        let checked = days
+            .par_iter()
+            .map(|&(year, puzzle)| {
+                let examples = load(&dir(root, year, puzzle.day())).unwrap();
+                for example in examples.iter() {
+                    for (part, result) in check(puzzle, example) {
+                        if let Err(e) = result {
+                            panic!("{} part {}:\n{}", example.path.display(), part, e);
+                        }
+                    }
+                }
+                examples.len()
+            })
+            .collect::<Vec<_>>();
+
+        assert!(checked.iter().all(|&n| n > 0));
+    }
+
+    #[test]
+    fn examples_of_the_workspace() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        assert!(is_example(&dir(root, 2022, 15).join("example.txt")));
+
+        let other = std::env::temp_dir().join(format!("advent-other-{}", std::process::id()));
+        let input = dir(&other, 2022, 15).join("example.txt");
+        std::fs::create_dir_all(input.parent().unwrap()).unwrap();
+        std::fs::write(&input, "").unwrap();
+        assert!(!is_example(&input));
+        std::fs::remove_dir_all(other).unwrap();
+    }
+
+    #[test]
+    fn mismatch() {
+        let root = std::env::temp_dir().join(format!("advent-examples-{}", std::process::id()));
+        let dir = dir(&root, 2022, 1);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("example.txt"), "1000\n\n2000\n").unwrap();
+        std::fs::write(dir.join("example.toml"), "part1 = \"1000\"").unwrap();
+
+        let examples = load(&dir).unwrap();
+        let day01 = crate::registry::get(2022, 1).unwrap();
+        assert_eq!(
+            check(day01, &examples[0]),
+            [(1, Err("- 1000\n+ 2000".to_string()))]
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+        assert!(load(&dir).unwrap().is_empty());
+    }
+}
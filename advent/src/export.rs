@@ -0,0 +1,330 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use gif::Repeat;
+use png::{BitDepth, ColorType};
+
+use crate::animate::Frame;
+
+/// Colours of the characters in the drawings, anything else is white.
+pub struct Palette {
+    colors: HashMap<char, [u8; 3]>,
+}
+
+// close to the colours of the puzzle pages
+const DEFAULT: &[(char, u32)] = &[
+    (' ', 0x000000),
+    ('.', 0x0f0f23),
+    ('#', 0xcccccc),
+    ('o', 0xe0c060),
+    ('+', 0xffff66),
+    ('s', 0xffff66),
+    ('H', 0xff4040),
+    ('E', 0x00cc00),
+    ('>', 0x66aaff),
+    ('v', 0x66aaff),
+    ('<', 0x66aaff),
+    ('^', 0x66aaff),
+    ('1', 0x3377cc),
+    ('2', 0x3377cc),
+    ('3', 0x3377cc),
+    ('4', 0x3377cc),
+    ('5', 0x3377cc),
+    ('6', 0x3377cc),
+    ('7', 0x3377cc),
+    ('8', 0x3377cc),
+    ('9', 0x3377cc),
+];
+
+const UNKNOWN: [u8; 3] = [0xff, 0xff, 0xff];
+
+fn rgb(color: u32) -> [u8; 3] {
+    let [_, r, g, b] = color.to_be_bytes();
+    [r, g, b]
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette {
+            colors: DEFAULT.iter().map(|&(c, color)| (c, rgb(color))).collect(),
+        }
+    }
+}
+
+impl Palette {
+    /// Changes a colour from `c=rrggbb`, `space` stands for the blank.
+    pub fn set(&mut self, entry: &str) -> Result<(), String> {
+        let invalid = || {
+            format!(
+                "expected a character and a colour like o=e0c060, found {:?}",
+                entry
+            )
+        };
+
+        let (c, color) = entry.split_once('=').ok_or_else(invalid)?;
+        let mut chars = c.chars();
+        let c = match (c, chars.next(), chars.next()) {
+            ("space", _, _) => ' ',
+            (_, Some(c), None) => c,
+            _ => return Err(invalid()),
+        };
+
+        let color = color.trim_start_matches('#');
+        if color.len() != 6 {
+            return Err(invalid());
+        }
+        let color = u32::from_str_radix(color, 16).map_err(|_| invalid())?;
+
+        self.colors.insert(c, rgb(color));
+        Ok(())
+    }
+
+    fn color(&self, c: char) -> [u8; 3] {
+        self.colors.get(&c).copied().unwrap_or(UNKNOWN)
+    }
+}
+
+/// How the frames are turned into pixels.
+pub struct Style {
+    pub palette: Palette,
+    /// Width and height of a character in pixels.
+    pub scale: usize,
+    /// Only the top rows of each frame, the tower of day 17 grows tall.
+    pub rows: Option<usize>,
+}
+
+/// Frames as indices into the colours, all of the same size.
+struct Canvas {
+    width: usize,
+    height: usize,
+    colors: Vec<[u8; 3]>,
+    frames: Vec<Vec<u8>>,
+}
+
+impl Style {
+    fn lines<'a>(&self, frame: &'a Frame) -> impl Iterator<Item = &'a str> {
+        frame.text.lines().take(self.rows.unwrap_or(usize::MAX))
+    }
+}
+
+fn rasterize(frames: &[Frame], style: &Style) -> Result<Canvas, String> {
+    let scale = style.scale.max(1);
+
+    // frames of a growing drawing are put in the top left corner of the largest
+    let columns = frames
+        .iter()
+        .flat_map(|f| style.lines(f).map(|l| l.chars().count()))
+        .max()
+        .unwrap_or(0);
+    let rows = frames
+        .iter()
+        .map(|f| style.lines(f).count())
+        .max()
+        .unwrap_or(0);
+    if columns == 0 || rows == 0 {
+        return Err("there is nothing to draw".to_string());
+    }
+
+    let mut colors = vec![style.palette.color(' ')];
+    let mut indices = HashMap::from([(' ', 0)]);
+    let mut canvas = Canvas {
+        width: columns * scale,
+        height: rows * scale,
+        colors: Vec::new(),
+        frames: Vec::with_capacity(frames.len()),
+    };
+
+    for frame in frames {
+        let mut pixels = vec![0; canvas.width * canvas.height];
+        for (y, line) in style.lines(frame).enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let index = match indices.get(&c) {
+                    Some(&index) => index,
+                    None => {
+                        let index = u8::try_from(colors.len())
+                            .map_err(|_| "a GIF has at most 256 colours".to_string())?;
+                        colors.push(style.palette.color(c));
+                        indices.insert(c, index);
+                        index
+                    }
+                };
+
+                for row in y * scale..(y + 1) * scale {
+                    let start = row * canvas.width + x * scale;
+                    pixels[start..start + scale].fill(index);
+                }
+            }
+        }
+        canvas.frames.push(pixels);
+    }
+
+    canvas.colors = colors;
+    Ok(canvas)
+}
+
+fn write_png(canvas: &Canvas, out: impl Write) -> Result<(), String> {
+    let failed = |e: png::EncodingError| format!("could not write the PNG: {}", e);
+
+    let mut encoder = png::Encoder::new(out, canvas.width as u32, canvas.height as u32);
+    encoder.set_color(ColorType::Rgb);
+    encoder.set_depth(BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(failed)?;
+
+    let last = canvas.frames.last().expect("a canvas has frames");
+    let data: Vec<u8> = last
+        .iter()
+        .flat_map(|&i| canvas.colors[i as usize])
+        .collect();
+    writer.write_image_data(&data).map_err(failed)?;
+    writer.finish().map_err(failed)
+}
+
+fn write_gif(canvas: &Canvas, fps: f64, out: impl Write) -> Result<(), String> {
+    let failed = |e: gif::EncodingError| format!("could not write the GIF: {}", e);
+    let too_large = |_| {
+        format!(
+            "a GIF is at most 65535 pixels wide and high, the frames are {}x{}",
+            canvas.width, canvas.height
+        )
+    };
+
+    let width = u16::try_from(canvas.width).map_err(too_large)?;
+    let height = u16::try_from(canvas.height).map_err(too_large)?;
+    let palette: Vec<u8> = canvas.colors.iter().flatten().copied().collect();
+    // in hundredths of a second, most viewers play anything faster at 10 fps
+    let delay = (100.0 / fps).round().clamp(2.0, u16::MAX as f64) as u16;
+
+    let mut encoder = gif::Encoder::new(out, width, height, &palette).map_err(failed)?;
+    encoder.set_repeat(Repeat::Infinite).map_err(failed)?;
+    for pixels in canvas.frames.iter() {
+        let mut frame = gif::Frame::from_indexed_pixels(width, height, pixels.as_slice(), None);
+        frame.delay = delay;
+        encoder.write_frame(&frame).map_err(failed)?;
+    }
+
+    encoder.into_inner().map(drop).map_err(failed)
+}
+
+/// Writes the frames to a GIF, or the last one to a PNG, as told by the extension.
+pub fn save(path: &Path, frames: &[Frame], style: &Style, fps: f64) -> Result<(), String> {
+    let extension = path.extension().and_then(|e| e.to_str());
+    let extension = extension.map(str::to_ascii_lowercase);
+    let frames = match extension.as_deref() {
+        Some("png") => &frames[frames.len().saturating_sub(1)..],
+        Some("gif") => frames,
+        _ => return Err(format!("{} isn't a .png or .gif file", path.display())),
+    };
+    let canvas = rasterize(frames, style)?;
+
+    let file =
+        File::create(path).map_err(|e| format!("could not create {}: {}", path.display(), e))?;
+    let mut out = BufWriter::new(file);
+    match extension.as_deref() {
+        Some("png") => write_png(&canvas, &mut out)?,
+        _ => write_gif(&canvas, fps, &mut out)?,
+    }
+
+    out.flush()
+        .map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(text: &str) -> Frame {
+        Frame {
+            step: 1,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn palette() {
+        let mut palette = Palette::default();
+        assert_eq!(palette.color('#'), [0xcc, 0xcc, 0xcc]);
+        assert_eq!(palette.color('?'), UNKNOWN);
+
+        palette.set("#=#808080").unwrap();
+        palette.set("space=102030").unwrap();
+        assert_eq!(palette.color('#'), [0x80, 0x80, 0x80]);
+        assert_eq!(palette.color(' '), [0x10, 0x20, 0x30]);
+
+        assert!(palette.set("#=80808").is_err());
+        assert!(palette.set("ab=808080").is_err());
+        assert!(palette.set("#").is_err());
+    }
+
+    #[test]
+    fn rasterized() {
+        let style = Style {
+            palette: Palette::default(),
+            scale: 2,
+            rows: None,
+        };
+        let canvas = rasterize(&[frame("#."), frame("o\n.#")], &style).unwrap();
+
+        assert_eq!((canvas.width, canvas.height), (4, 4));
+        assert_eq!(
+            canvas.colors,
+            [
+                [0, 0, 0],
+                [0xcc, 0xcc, 0xcc],
+                [0x0f, 0x0f, 0x23],
+                [0xe0, 0xc0, 0x60]
+            ]
+        );
+        // the smaller first frame is padded with blanks
+        assert_eq!(
+            canvas.frames[0],
+            [1, 1, 2, 2, 1, 1, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            canvas.frames[1],
+            [3, 3, 0, 0, 3, 3, 0, 0, 2, 2, 1, 1, 2, 2, 1, 1]
+        );
+
+        let top = Style {
+            rows: Some(1),
+            ..style
+        };
+        assert_eq!(rasterize(&[frame("o\n.#")], &top).unwrap().height, 2);
+        assert!(rasterize(&[frame("")], &top).is_err());
+    }
+
+    #[test]
+    fn saved() {
+        let dir = std::env::temp_dir();
+        let style = Style {
+            palette: Palette::default(),
+            scale: 3,
+            rows: None,
+        };
+        let frames = [frame("#.\n.#"), frame("o.\n.#")];
+
+        let path = dir.join(format!("advent-export-{}.png", std::process::id()));
+        save(&path, &frames, &style, 10.0).unwrap();
+        let decoder = png::Decoder::new(std::io::BufReader::new(File::open(&path).unwrap()));
+        let reader = decoder.read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (6, 6));
+        std::fs::remove_file(&path).unwrap();
+
+        let path = dir.join(format!("advent-export-{}.gif", std::process::id()));
+        save(&path, &frames, &style, 10.0).unwrap();
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(File::open(&path).unwrap())
+            .unwrap();
+        let mut delays = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            delays.push(frame.delay);
+        }
+        assert_eq!(delays, [10, 10]);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(save(Path::new("frames.txt"), &frames, &style, 10.0).is_err());
+    }
+}
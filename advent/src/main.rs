@@ -1,194 +1,803 @@
-use std::fs::read_to_string;
+use std::{
+    fs::write,
+    path::{Path, PathBuf},
+    process::exit,
+    time::{Duration, Instant},
+};
+
+use clap::{error::ErrorKind, Args, CommandFactory, Parser, Subcommand};
+use common::{Answer, Error};
+
+mod all;
+mod animate;
+mod answers;
+mod bench;
+mod client;
+mod config;
+mod examples;
+mod export;
+mod output;
+mod registry;
+mod repl;
+mod scaffold;
+mod serve;
+#[cfg(test)]
+mod stub;
+mod submit;
+mod watch;
+
+use output::{Format, Printer};
+use registry::Puzzle;
+
+#[derive(Parser)]
+#[command(
+    about = "Advent of Code solutions",
+    args_conflicts_with_subcommands = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run every registered day, the output stays in day order
+    All(AllArgs),
+    /// Time parsing and both parts over many iterations
+    Bench(BenchArgs),
+    /// Compare the answers of every day with the recorded ones
+    Verify(VerifyArgs),
+    /// Check the answers of the examples in examples/YYYY/dayNN
+    Examples(ExamplesArgs),
+    /// Check what the solution of a day assumes about its input without solving it
+    Check(CheckArgs),
+    /// Solve a day again whenever its input or parameter file changes
+    Watch(WatchArgs),
+    /// Prompt for choosing a day, changing its parameters and solving it again
+    Repl(ReplArgs),
+    /// Play the simulation of day 9, 14, 17, 22, 23 or 24 in the terminal or save it as an image
+    Animate(AnimateArgs),
+    /// Answer POST /YYYY/day/N?part=P&param=KEY=VALUE with the input as body in JSON
+    Serve(ServeArgs),
+    /// Solve an input read from stdin for `serve` and print the answers as JSON
+    #[command(hide = true)]
+    Worker(WorkerArgs),
+    /// Download the input of a day to inputs/YYYY/dayNN.txt
+    Fetch(FetchArgs),
+    /// Solve a part and post the answer, the verdicts are kept in a history
+    Submit(SubmitArgs),
+    /// Generate the crate of a day and register it with the workspace and the runner
+    New(NewArgs),
+}
+
+#[derive(Args)]
+struct AllArgs {
+    /// Year of the puzzles, defaults to the most recent one
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Number of days solved at the same time, 0 uses one thread per core
+    #[arg(short, long, default_value_t = 1)]
+    jobs: usize,
+
+    /// Output format of the answers
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Year of the puzzles, defaults to the most recent one
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Only benchmark a single day
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Number of runs per day
+    #[arg(short = 'n', long, default_value_t = 10)]
+    iterations: usize,
+
+    /// File for the JSON report
+    #[arg(short, long, default_value = "bench.json")]
+    output: PathBuf,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Year of the puzzles, defaults to the most recent one
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Only verify a single day
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// File with the recorded answers
+    #[arg(short, long, default_value = "answers.toml")]
+    answers: PathBuf,
+}
+
+#[derive(Args)]
+struct ExamplesArgs {
+    /// Year of the puzzles, defaults to the most recent one
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Only check the examples of a single day
+    #[arg(short, long)]
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    /// Year of the puzzle, defaults to the most recent one
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Day of the puzzle
+    #[arg(short, long)]
+    day: u8,
+
+    /// Input file, defaults to inputs/YYYY/dayNN.txt
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Overrides a parameter of the puzzle, the value is TOML like `bounds=[0, 20]`
+    #[arg(short = 'P', long = "param", value_name = "KEY=VALUE")]
+    params: Vec<String>,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Year of the puzzle, defaults to the most recent one
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Day of the puzzle
+    #[arg(short, long)]
+    day: u8,
+
+    /// Input file, defaults to inputs/YYYY/dayNN.txt
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Overrides a parameter of the puzzle, the value is TOML like `bounds=[0, 20]`
+    #[arg(short = 'P', long = "param", value_name = "KEY=VALUE")]
+    params: Vec<String>,
+
+    /// Milliseconds between checks of the files
+    #[arg(long, default_value_t = 250)]
+    interval: u64,
+}
+
+#[derive(Args)]
+struct ReplArgs {
+    /// Year of the puzzles, defaults to the most recent one
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Day to start with
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Input to start with, defaults to inputs/YYYY/dayNN.txt of the day
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct AnimateArgs {
+    /// Year of the puzzle, defaults to the most recent one
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Day of the puzzle
+    #[arg(short, long)]
+    day: u8,
+
+    /// Part whose simulation is shown
+    #[arg(short, long, default_value_t = 1)]
+    part: u8,
+
+    /// Input file, defaults to inputs/YYYY/dayNN.txt
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Overrides a parameter of the puzzle, the value is TOML like `bounds=[0, 20]`
+    #[arg(short = 'P', long = "param", value_name = "KEY=VALUE")]
+    params: Vec<String>,
+
+    /// Frames per second, can be changed while playing
+    #[arg(long, default_value_t = 10.0)]
+    fps: f64,
+
+    /// Only draw every Nth step of the simulation
+    #[arg(long, default_value_t = 1)]
+    every: usize,
+
+    /// Stop the simulation after this many frames
+    #[arg(long, default_value_t = 1000)]
+    limit: usize,
+
+    /// Write the frames to a .gif, or the last one to a .png, instead of playing them
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Pixels per character of the drawings in the file
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+
+    /// Colour of a character in the file, e.g. `--palette o=e0c060` or `--palette space=000000`
+    #[arg(long)]
+    palette: Vec<String>,
+
+    /// Only the top rows of the drawings go into the file
+    #[arg(long)]
+    rows: Option<usize>,
+}
+
+#[derive(Args)]
+struct ServeArgs {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1")]
+    host: String,
+
+    /// Port to listen on
+    #[arg(short, long, default_value_t = 8022)]
+    port: u16,
+
+    /// Largest input accepted, in bytes
+    #[arg(long, default_value_t = 1 << 20)]
+    max_input: usize,
+
+    /// Seconds after which a request is answered with a timeout
+    #[arg(long, default_value_t = 10)]
+    timeout: u64,
+
+    /// Number of inputs solved at the same time, 0 uses one per core
+    #[arg(short, long, default_value_t = 0)]
+    jobs: usize,
+}
+
+#[derive(Args)]
+struct WorkerArgs {
+    #[arg(short, long)]
+    year: u16,
+
+    #[arg(short, long)]
+    day: u8,
+
+    #[arg(short, long)]
+    part: Option<u8>,
+
+    #[arg(short = 'P', long = "param", value_name = "KEY=VALUE")]
+    params: Vec<String>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Year of the puzzles, defaults to the most recent one
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Day of the puzzle
+    #[arg(short, long)]
+    day: u8,
+
+    /// Website to download from, the session cookie is read from AOC_SESSION
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Year of the puzzles, defaults to the most recent one
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Day of the puzzle
+    #[arg(short, long)]
+    day: u8,
+
+    /// Part of the puzzle
+    #[arg(short, long)]
+    part: u8,
+
+    /// Input file, defaults to inputs/YYYY/dayNN.txt
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// File with the answers submitted so far
+    #[arg(long, default_value = "submissions.toml")]
+    history: PathBuf,
+
+    /// Website to post to, the session cookie is read from AOC_SESSION
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Year of the puzzles, defaults to the most recent one
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Day of the puzzle
+    day: u8,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Year of the puzzles, defaults to the most recent one
+    #[arg(short, long)]
+    year: Option<u16>,
+
+    /// Day of the puzzle
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Only run a single part of the puzzle
+    #[arg(short, long)]
+    part: Option<u8>,
+
+    /// Input file, defaults to inputs/YYYY/dayNN.txt
+    ///
+    /// Parameters of the puzzle are read from a TOML file next to it, e.g. inputs/YYYY/dayNN.toml
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Overrides a parameter of the puzzle, the value is TOML like `bounds=[0, 20]`
+    #[arg(short = 'P', long = "param", value_name = "KEY=VALUE")]
+    params: Vec<String>,
+
+    /// Output format of the answers
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+fn default_input(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/{}/day{:02}.txt", year, day))
+}
+
+/// Renders an error with the offending input line and a caret below the column.
+fn diagnostic(err: &Error, path: &Path, input: &str) -> String {
+    let Some((line, column)) = err.location() else {
+        return err.to_string();
+    };
+
+    let source = input.lines().nth(line - 1).unwrap_or("");
+    let number = line.to_string();
+    let pad = " ".repeat(number.len());
+
+    format!(
+        "{}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}^",
+        err,
+        pad,
+        path.display(),
+        line,
+        column,
+        pad,
+        number,
+        source,
+        pad,
+        " ".repeat(column - 1)
+    )
+}
+
+fn read_input(path: &Path) -> Result<String, String> {
+    common::input::read(path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+fn run_all(args: AllArgs) -> Result<(), String> {
+    let year = args.year.unwrap_or_else(registry::latest);
+    let puzzles = registry::days(year)?;
+
+    let start = Instant::now();
+    let mut printer = Printer::new(args.format);
+    let outcomes = all::solve_all(year, puzzles, args.jobs, |o| printer.day(o))?;
+
+    printer.finish(&outcomes, true);
+    if args.format == Format::Text {
+        println!("total {:.1?}", start.elapsed());
+    }
+
+    let failed = outcomes.iter().filter(|o| !o.is_ok()).count();
+    if failed > 0 {
+        return Err(format!("{} of the days failed", failed));
+    }
+
+    Ok(())
+}
+
+fn run_bench(args: BenchArgs) -> Result<(), String> {
+    let year = args.year.unwrap_or_else(registry::latest);
+    let puzzles = match args.day {
+        Some(day) => vec![registry::get(year, day)?],
+        None => registry::days(year)?.to_vec(),
+    };
+    if args.iterations == 0 {
+        return Err("at least one iteration is needed".to_string());
+    }
+
+    let mut days = Vec::new();
+    for puzzle in puzzles {
+        let path = default_input(year, puzzle.day());
+        let input = read_input(&path)?;
+        let params = config::load(&path)?;
+
+        let report = bench::bench_day(puzzle, &input, &params, args.iterations)
+            .map_err(|e| diagnostic(&e, &path, &input))?;
+        bench::print_day(&report);
+        days.push(report);
+    }
+
+    let report = bench::Report {
+        year,
+        unit: "ns",
+        iterations: args.iterations,
+        days,
+    };
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    write(&args.output, json)
+        .map_err(|e| format!("could not write {}: {}", args.output.display(), e))?;
+
+    println!("report written to {}", args.output.display());
+
+    Ok(())
+}
+
+fn run_fetch(args: FetchArgs) -> Result<(), String> {
+    let year = args.year.unwrap_or_else(registry::latest);
+    let path = default_input(year, args.day);
+    let client = client::Client::from_env(&args.base_url)?;
+
+    client::fetch_input(&client, year, args.day, &path)?;
+    println!("saved {}", path.display());
+
+    Ok(())
+}
+
+fn run_submit(args: SubmitArgs) -> Result<(), String> {
+    let year = args.year.unwrap_or_else(registry::latest);
+    let puzzle = registry::get(year, args.day)?;
+    if args.part == 0 || args.part > puzzle.parts() {
+        return Err(format!("day {} has no part {}", args.day, args.part));
+    }
+    let path = args.input.unwrap_or_else(|| default_input(year, args.day));
+
+    // a single part was asked for, so there is a single answer
+    let answer = all::solve(year, puzzle, &[args.part], &path, &[])
+        .parts
+        .remove(0)
+        .answer?;
+    if let Answer::Pixels(_) = answer {
+        return Err(format!(
+            "the answer has to be read off the output, submit it by hand:\n{}",
+            answer
+        ));
+    }
+    let answer = answer.to_string();
+
+    let client = client::Client::from_env(&args.base_url)?;
+    let verdict = submit::submit(
+        &client,
+        &args.history,
+        (year, args.day, args.part),
+        &answer,
+        |d| {
+            println!("waiting {:?} before submitting", d);
+            std::thread::sleep(d);
+        },
+    )?;
+
+    println!(
+        "day {} part {}: {} is {}",
+        args.day, args.part, answer, verdict
+    );
+    if verdict != submit::Verdict::Correct {
+        return Err(format!("{} is {}", answer, verdict));
+    }
+
+    Ok(())
+}
+
+fn run_new(args: NewArgs) -> Result<(), String> {
+    // the runner is started from the root of the workspace, like for the inputs
+    let year = args.year.unwrap_or_else(registry::latest);
+    for path in scaffold::new_day(Path::new("."), year, args.day)? {
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn run_verify(args: VerifyArgs) -> Result<(), String> {
+    let answers = answers::Answers::parse(&read_input(&args.answers)?)
+        .map_err(|e| format!("invalid {}: {}", args.answers.display(), e))?;
+
+    let year = args.year.unwrap_or_else(registry::latest);
+    let puzzles = match args.day {
+        Some(day) => vec![registry::get(year, day)?],
+        None => registry::days(year)?.to_vec(),
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for puzzle in puzzles {
+        let day = puzzle.day();
+        let parts = (1..=puzzle.parts()).collect::<Vec<_>>();
+
+        let outcome = all::solve(year, puzzle, &parts, &default_input(year, day), &[]);
+
+        for p in outcome.parts {
+            // the recorded answers are text like the output
+            let (part, result) = (p.part, p.answer.map(|a| a.to_string()));
+            let label = format!("Day {:02} part {}", day, part);
+            match (answers.get(year, day, part), result) {
+                (Some(expected), Ok(actual)) if expected == actual => {
+                    println!("{}: pass", label);
+                    passed += 1;
+                }
+                (Some(expected), Ok(actual)) => {
+                    println!("{}: FAIL\n{}", label, answers::diff(expected, &actual));
+                    failed += 1;
+                }
+                (_, Err(e)) => {
+                    println!("{}: FAIL\n{}", label, e);
+                    failed += 1;
+                }
+                (None, Ok(actual)) if actual.contains('\n') => {
+                    println!("{}: missing, got\n{}", label, actual);
+                    missing += 1;
+                }
+                (None, Ok(actual)) => {
+                    println!("{}: missing, got {}", label, actual);
+                    missing += 1;
+                }
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    if failed > 0 {
+        return Err(format!("{} of the answers don't match", failed));
+    }
+
+    Ok(())
+}
+
+fn run_examples(args: ExamplesArgs) -> Result<(), String> {
+    let year = args.year.unwrap_or_else(registry::latest);
+    let puzzles = match args.day {
+        Some(day) => vec![registry::get(year, day)?],
+        None => registry::days(year)?.to_vec(),
+    };
+
+    let (mut passed, mut failed) = (0, 0);
+    for puzzle in puzzles {
+        for example in examples::load(&examples::dir(Path::new("."), year, puzzle.day()))? {
+            for (part, result) in examples::check(puzzle, &example) {
+                let label = format!("{} part {}", example.path.display(), part);
+                match result {
+                    Ok(()) => {
+                        println!("{}: pass", label);
+                        passed += 1;
+                    }
+                    Err(e) => {
+                        println!("{}: FAIL\n{}", label, e);
+                        failed += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    println!("{} passed, {} failed", passed, failed);
+
+    if failed > 0 {
+        return Err(format!("{} of the examples don't match", failed));
+    }
+
+    Ok(())
+}
+
+fn run_check(args: CheckArgs) -> Result<(), String> {
+    let year = args.year.unwrap_or_else(registry::latest);
+    let puzzle = registry::get(year, args.day)?;
+    let path = args.input.unwrap_or_else(|| default_input(year, args.day));
+    let input = read_input(&path)?;
+    let mut params = config::load(&path)?;
+    config::apply(&mut params, &args.params)?;
+
+    let assumptions = puzzle
+        .check(&input, &params)
+        .map_err(|e| diagnostic(&e, &path, &input))?;
+    if assumptions.is_empty() {
+        println!("day {} doesn't assume anything about its input", args.day);
+        return Ok(());
+    }
+
+    let mut failed = 0;
+    for a in assumptions.iter() {
+        match &a.result {
+            Ok(()) => println!("{}: holds", a.text),
+            Err(e) => {
+                println!("{}: FAIL\n{}", a.text, e);
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!(
+            "{} of {} assumptions don't hold for {}",
+            failed,
+            assumptions.len(),
+            path.display()
+        ));
+    }
+
+    Ok(())
+}
+
+fn run_watch(args: WatchArgs) -> Result<(), String> {
+    let year = args.year.unwrap_or_else(registry::latest);
+    let puzzle = registry::get(year, args.day)?;
+    let path = args.input.unwrap_or_else(|| default_input(year, args.day));
+    let parts = (1..=puzzle.parts()).collect::<Vec<_>>();
+
+    let mut watcher = watch::Watcher::new(vec![path.clone(), config::path(&path)]);
+    let mut previous = None;
+    loop {
+        let outcome = all::solve(year, puzzle, &parts, &path, &args.params);
+        println!("{}\n", watch::compare(previous.as_ref(), &outcome));
+        previous = Some(outcome);
+
+        watcher.wait(Duration::from_millis(args.interval));
+    }
+}
+
+fn run_animate(args: AnimateArgs) -> Result<(), String> {
+    let year = args.year.unwrap_or_else(registry::latest);
+    let path = args.input.unwrap_or_else(|| default_input(year, args.day));
+    let input = read_input(&path)?;
+    let mut params = config::load(&path)?;
+    config::apply(&mut params, &args.params)?;
+
+    let recorder = animate::Recorder::new(args.every, args.limit);
+    let (frames, end) =
+        animate::record(year, args.day, args.part, &path, &input, &params, recorder)?;
+
+    let Some(output) = args.output else {
+        return animate::play(frames, end, args.fps);
+    };
+    let mut style = export::Style {
+        palette: export::Palette::default(),
+        scale: args.scale,
+        rows: args.rows,
+    };
+    for entry in args.palette.iter() {
+        style.palette.set(entry)?;
+    }
+    export::save(&output, &frames, &style, args.fps)?;
+    println!(
+        "{} frames, {}, written to {}",
+        frames.len(),
+        end,
+        output.display()
+    );
+
+    Ok(())
+}
+
+fn run_serve(args: ServeArgs) -> Result<(), String> {
+    let address = format!("{}:{}", args.host, args.port);
+    let server = tiny_http::Server::http(&address)
+        .map_err(|e| format!("could not listen on {}: {}", address, e))?;
+    let jobs = match args.jobs {
+        0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        jobs => jobs,
+    };
+
+    let worker =
+        std::env::current_exe().map_err(|e| format!("could not find the executable: {}", e))?;
+
+    // the port may have been picked by the system
+    println!("listening on http://{}", server.server_addr());
+    serve::serve(
+        server,
+        serve::Limits {
+            max_input: args.max_input,
+            timeout: Duration::from_secs(args.timeout),
+            jobs,
+            worker,
+        },
+    );
+
+    Ok(())
+}
+
+fn run_day(year: u16, puzzle: &dyn Puzzle, args: RunArgs) -> Result<(), String> {
+    let path = args
+        .input
+        .unwrap_or_else(|| default_input(year, puzzle.day()));
+
+    let parts = match args.part {
+        Some(p) if p == 0 || p > puzzle.parts() => {
+            return Err(format!("day {} has no part {}", puzzle.day(), p));
+        }
+        Some(p) => vec![p],
+        None => (1..=puzzle.parts()).collect(),
+    };
+
+    let outcome = all::solve(year, puzzle, &parts, &path, &args.params);
+
+    if args.format != Format::Text {
+        let mut printer = Printer::new(args.format);
+        printer.day(&outcome);
+        printer.finish(std::slice::from_ref(&outcome), false);
+
+        if !outcome.is_ok() {
+            return Err(format!("day {} failed", puzzle.day()));
+        }
+        return Ok(());
+    }
+
+    println!("Day {:02}", puzzle.day());
+    for p in outcome.parts {
+        let result = p.answer?.to_string();
+        if result.contains('\n') {
+            println!("puzzle {}: \n{}", p.part, result);
+        } else {
+            println!("puzzle {}: {}", p.part, result);
+        }
+    }
+
+    Ok(())
+}
 
 fn main() {
-    // println!("Day 01");
-    // let input = read_to_string("inputs/day01.txt").unwrap();
-    // let result = day01::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day01::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 02");
-    // let input = read_to_string("inputs/day02.txt").unwrap();
-    // let result = day02::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day02::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 03");
-    // let input = read_to_string("inputs/day03.txt").unwrap();
-    // let result = day03::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day03::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 04");
-    // let input = read_to_string("inputs/day04.txt").unwrap();
-    // let result = day04::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day04::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 05");
-    // let input = read_to_string("inputs/day05.txt").unwrap();
-    // let result = day05::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day05::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 06");
-    // let input = read_to_string("inputs/day06.txt").unwrap();
-    // let result = day06::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day06::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 07");
-    // let input = read_to_string("inputs/day07.txt").unwrap();
-    // let result = day07::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day07::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 08");
-    // let input = read_to_string("inputs/day08.txt").unwrap();
-    // let result = day08::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day08::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 09");
-    // let input = read_to_string("inputs/day09.txt").unwrap();
-    // let result = day09::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day09::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 10");
-    // let input = read_to_string("inputs/day10.txt").unwrap();
-    // let result = day10::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day10::puzzle_2(&input);
-    // println!("puzzle 2: \n{}", result);
-
-    // println!("Day 11");
-    // let input = read_to_string("inputs/day11.txt").unwrap();
-    // let result = day11::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day11::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 12");
-    // let input = read_to_string("inputs/day12.txt").unwrap();
-    // let result = day12::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day12::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 13");
-    // let input = read_to_string("inputs/day13.txt").unwrap();
-    // let result = day13::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day13::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 14");
-    // let input = read_to_string("inputs/day14.txt").unwrap();
-    // let result = day14::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day14::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 15");
-    // let input = read_to_string("inputs/day15.txt").unwrap();
-    // let result = day15::puzzle_1(&input, 2000000);
-    // println!("puzzle 1: {}", result);
-    // let result = day15::puzzle_2(&input, (0, 4000000));
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 16");
-    // let input = read_to_string("inputs/day16.txt").unwrap();
-    // let result = day16::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day16::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 17");
-    // let input = read_to_string("inputs/day17.txt").unwrap();
-    // let result = day17::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day17::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 18");
-    // let input = read_to_string("inputs/day18.txt").unwrap();
-    // let result = day18::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day18::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 19");
-    // let input = read_to_string("inputs/day19.txt").unwrap();
-    // let result = day19::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day19::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 20");
-    // let input = read_to_string("inputs/day20.txt").unwrap();
-    // let result = day20::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day20::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 21");
-    // let input = read_to_string("inputs/day21.txt").unwrap();
-    // let result = day21::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day21::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 22");
-    // let input = read_to_string("inputs/day22.txt").unwrap();
-    // let result = day22::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-
-    // use day22::Dir::*;
-    // let lookup = HashMap::from([
-    //     ((1, 0, Up), (0, 3, Right)),
-    //     ((0, 3, Left), (1, 0, Down)),
-    //     ((2, 0, Up), (0, 3, Up)),
-    //     ((0, 3, Down), (2, 0, Down)),
-    //     ((2, 0, Right), (1, 2, Left)),
-    //     ((1, 2, Right), (2, 0, Left)),
-    //     ((2, 0, Down), (1, 1, Left)),
-    //     ((1, 1, Right), (2, 0, Up)),
-    //     ((1, 2, Down), (0, 3, Left)),
-    //     ((0, 3, Right), (1, 2, Up)),
-    //     ((0, 2, Left), (1, 0, Right)),
-    //     ((1, 0, Left), (0, 2, Right)),
-    //     ((0, 2, Up), (1, 1, Right)),
-    //     ((1, 1, Left), (0, 2, Down)),
-    // ]);
-    // let result = day22::puzzle_2(&input, 50, lookup);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 23");
-    // let input = read_to_string("inputs/day23.txt").unwrap();
-    // let result = day23::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day23::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    // println!("Day 24");
-    // let input = read_to_string("inputs/day24.txt").unwrap();
-    // let result = day24::puzzle_1(&input);
-    // println!("puzzle 1: {}", result);
-    // let result = day24::puzzle_2(&input);
-    // println!("puzzle 2: {}", result);
-
-    println!("Day 25");
-    let input = read_to_string("inputs/day25.txt").unwrap();
-    let result = day25::puzzle_1(&input);
-    println!("puzzle 1: {}", result);
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Some(Command::All(args)) => run_all(args),
+        Some(Command::Bench(args)) => run_bench(args),
+        Some(Command::Verify(args)) => run_verify(args),
+        Some(Command::Examples(args)) => run_examples(args),
+        Some(Command::Check(args)) => run_check(args),
+        Some(Command::Watch(args)) => run_watch(args),
+        Some(Command::Repl(args)) => repl::run(
+            args.year.unwrap_or_else(registry::latest),
+            args.day,
+            args.input,
+        ),
+        Some(Command::Animate(args)) => run_animate(args),
+        Some(Command::Serve(args)) => run_serve(args),
+        Some(Command::Worker(args)) => serve::work(args.year, args.day, args.part, &args.params),
+        Some(Command::Fetch(args)) => run_fetch(args),
+        Some(Command::Submit(args)) => run_submit(args),
+        Some(Command::New(args)) => run_new(args),
+        None => {
+            let Some(day) = cli.run.day else {
+                Cli::command()
+                    .error(
+                        ErrorKind::MissingRequiredArgument,
+                        "either --day or a subcommand is required",
+                    )
+                    .exit();
+            };
+            let year = cli.run.year.unwrap_or_else(registry::latest);
+            registry::get(year, day).and_then(|puzzle| run_day(year, puzzle, cli.run))
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        exit(1);
+    }
 }
@@ -0,0 +1,271 @@
+use clap::ValueEnum;
+use common::Answer;
+use serde::{Serialize, Serializer};
+
+use crate::all::Outcome;
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// `puzzle N: answer` lines, `all` ends with a summary table
+    Text,
+    /// A single array with one object per part
+    Json,
+    /// A header and one row per part
+    Csv,
+}
+
+/// One part in the machine readable formats.
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    #[serde(serialize_with = "typed_answer")]
+    answer: Option<&'a Answer>,
+    elapsed_ns: u64,
+    error: Option<&'a str>,
+}
+
+// integers are numbers and pixels are their rows like `#..#`, so they can be compared as is
+fn typed_answer<S: Serializer>(answer: &Option<&Answer>, s: S) -> Result<S::Ok, S::Error> {
+    match answer {
+        None => s.serialize_none(),
+        Some(Answer::Integer(n)) => s.serialize_i128(*n),
+        Some(Answer::Text(text)) => s.serialize_str(text),
+        Some(pixels @ Answer::Pixels(_)) => s.collect_seq(pixels.to_string().lines()),
+    }
+}
+
+fn records(outcome: &Outcome) -> impl Iterator<Item = Record<'_>> {
+    outcome.parts.iter().map(move |p| Record {
+        year: outcome.year,
+        day: outcome.day,
+        part: p.part,
+        answer: p.answer.as_ref().ok(),
+        elapsed_ns: p.elapsed.as_nanos() as u64,
+        // the rendered diagnostic starts with the error message on its own line
+        error: p.answer.as_ref().err().and_then(|e| e.lines().next()),
+    })
+}
+
+pub fn json(outcomes: &[Outcome]) -> String {
+    let records = outcomes.iter().flat_map(records).collect::<Vec<_>>();
+
+    serde_json::to_string_pretty(&records).expect("records are always serializable")
+}
+
+const CSV_HEADER: &str = "year,day,part,answer,elapsed_ns,error";
+
+// quotes a field if needed, so multi-line answers survive as well
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn csv_rows(outcome: &Outcome) -> String {
+    records(outcome)
+        .map(|r| {
+            format!(
+                "{},{},{},{},{},{}",
+                r.year,
+                r.day,
+                r.part,
+                csv_field(&r.answer.map(Answer::to_string).unwrap_or_default()),
+                r.elapsed_ns,
+                csv_field(r.error.unwrap_or(""))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn print_text(outcome: &Outcome) {
+    println!("Day {:02}", outcome.day);
+    for p in outcome.parts.iter() {
+        match &p.answer {
+            Ok(answer @ Answer::Pixels(_)) => println!("puzzle {}: \n{}", p.part, answer),
+            Ok(answer) => println!("puzzle {}: {}", p.part, answer),
+            Err(e) => println!("puzzle {}: error: {}", p.part, e),
+        }
+    }
+}
+
+/// Prints the results of days as they come in, JSON has to wait for all of them.
+pub struct Printer {
+    format: Format,
+    started: bool,
+}
+
+impl Printer {
+    pub fn new(format: Format) -> Self {
+        Printer {
+            format,
+            started: false,
+        }
+    }
+
+    pub fn day(&mut self, outcome: &Outcome) {
+        match self.format {
+            Format::Text => print_text(outcome),
+            Format::Csv => {
+                if !self.started {
+                    println!("{}", CSV_HEADER);
+                }
+                println!("{}", csv_rows(outcome));
+            }
+            Format::Json => {}
+        }
+        self.started = true;
+    }
+
+    /// `summary` adds the table of all days to the text output.
+    pub fn finish(&self, outcomes: &[Outcome], summary: bool) {
+        match self.format {
+            Format::Text if summary => println!("\n{}", self::summary(outcomes)),
+            Format::Json => println!("{}", json(outcomes)),
+            Format::Text | Format::Csv => {}
+        }
+    }
+}
+
+/// Table with one row per day, multi-line answers are left to the output above it.
+pub fn summary(outcomes: &[Outcome]) -> String {
+    let part_count = outcomes.iter().map(|o| o.parts.len()).max().unwrap_or(0);
+
+    let mut header = vec!["Day".to_string(), "Status".to_string()];
+    header.extend((1..=part_count).map(|p| format!("Part {}", p)));
+    header.push("Time".to_string());
+
+    let mut rows = vec![header];
+    for outcome in outcomes {
+        let mut row = vec![
+            format!("{:02}", outcome.day),
+            if outcome.is_ok() { "ok" } else { "error" }.to_string(),
+        ];
+        for i in 0..part_count {
+            row.push(match outcome.parts.get(i).map(|p| &p.answer) {
+                Some(Ok(Answer::Pixels(_))) => "(multi-line)".to_string(),
+                Some(Ok(answer)) => answer.to_string(),
+                Some(Err(_)) | None => "-".to_string(),
+            });
+        }
+        row.push(format!("{:.1?}", outcome.elapsed));
+        rows.push(row);
+    }
+
+    let widths = (0..rows[0].len())
+        .map(|c| rows.iter().map(|r| r[c].len()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    rows.iter()
+        .map(|row| {
+            let cells = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, &w)| format!("{:<w$}", cell, w = w))
+                .collect::<Vec<_>>();
+            cells.join("  ").trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::all::PartOutcome;
+
+    fn outcome(day: u8, answers: Vec<Result<Answer, &str>>, elapsed: Duration) -> Outcome {
+        Outcome {
+            year: 2022,
+            day,
+            parts: answers
+                .into_iter()
+                .enumerate()
+                .map(|(i, a)| PartOutcome {
+                    part: i as u8 + 1,
+                    answer: a.map_err(str::to_string),
+                    elapsed: Duration::from_nanos(100),
+                })
+                .collect(),
+            elapsed,
+        }
+    }
+
+    fn outcomes() -> Vec<Outcome> {
+        vec![
+            outcome(
+                1,
+                vec![Ok(24000.into()), Ok(45000.into())],
+                Duration::from_micros(1500),
+            ),
+            outcome(
+                10,
+                vec![
+                    Ok(13140.into()),
+                    Ok(Answer::Pixels(vec![vec![true, false], vec![false, true]])),
+                ],
+                Duration::from_millis(2),
+            ),
+            outcome(
+                25,
+                vec![Err(
+                    "day 25, line 1, column 1: expected a SNAFU number\n --> ...",
+                )],
+                Duration::ZERO,
+            ),
+        ]
+    }
+
+    #[test]
+    fn summary_table() {
+        assert_eq!(
+            summary(&outcomes()),
+            "\
+Day  Status  Part 1  Part 2        Time
+01   ok      24000   45000         1.5ms
+10   ok      13140   (multi-line)  2.0ms
+25   error   -       -             0.0ns"
+        );
+    }
+
+    #[test]
+    fn json_records() {
+        let json = json(&outcomes());
+        let records = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+
+        assert_eq!(records.as_array().unwrap().len(), 5);
+        assert_eq!(records[3]["year"], 2022);
+        assert_eq!(records[0]["answer"], 24000);
+        assert_eq!(records[3]["answer"], serde_json::json!(["#.", ".#"]));
+        assert_eq!(records[3]["elapsed_ns"], 100);
+        assert_eq!(records[4]["answer"], serde_json::Value::Null);
+        assert_eq!(
+            records[4]["error"],
+            "day 25, line 1, column 1: expected a SNAFU number"
+        );
+
+        let text = super::json(&[outcome(5, vec![Ok("CMZ".into())], Duration::ZERO)]);
+        let records = serde_json::from_str::<serde_json::Value>(&text).unwrap();
+        assert_eq!(records[0]["answer"], "CMZ");
+    }
+
+    #[test]
+    fn csv_quoting() {
+        let outcomes = outcomes();
+
+        assert_eq!(
+            csv_rows(&outcomes[1]),
+            "2022,10,1,13140,100,\n2022,10,2,\"#.\n.#\",100,"
+        );
+        assert_eq!(
+            csv_rows(&outcomes[2]),
+            "2022,25,1,,100,\"day 25, line 1, column 1: expected a SNAFU number\""
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+use common::{Answer, Assumption, Error, Result, Solution};
+use toml::{Table, Value};
+
+/// Object safe view of a [`Solution`], so days with different input
+/// and parameter types can be kept in a single list.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    fn parts(&self) -> u8;
+
+    /// Parses the input once and solves the given parts, `params` come from a config file.
+    fn run(&self, input: &str, parts: &[u8], params: &Table) -> Result<Vec<PartResult>>;
+
+    /// Runs all parts once and measures parsing and each part separately.
+    fn time(&self, input: &str, params: &Table) -> Result<Timing>;
+
+    /// Parses the input and checks what the solution assumes about it.
+    fn check(&self, input: &str, params: &Table) -> Result<Vec<Assumption>>;
+}
+
+/// Answer of a single part and the time it took to solve it.
+#[derive(Debug)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+/// Durations of a single run, split into parsing and solving the parts.
+pub struct Timing {
+    pub parse: Duration,
+    pub parts: Vec<Duration>,
+}
+
+struct Entry<S>(PhantomData<fn() -> S>);
+
+const fn entry<S: Solution>() -> Entry<S> {
+    Entry(PhantomData)
+}
+
+/// Parameters of a solution from a table, fields missing in it keep their default.
+pub fn read_params<S: Solution>(params: &Table) -> Result<S::Params> {
+    if params.is_empty() {
+        return Ok(S::Params::default());
+    }
+
+    Value::Table(params.clone()).try_into().map_err(|e| {
+        Error::solve(
+            S::DAY,
+            format!("invalid parameters: {}", e.to_string().trim_end()),
+        )
+    })
+}
+
+impl<S: Solution> Puzzle for Entry<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn run(&self, input: &str, parts: &[u8], params: &Table) -> Result<Vec<PartResult>> {
+        let params = read_params::<S>(params)?;
+        let parsed = S::load(input)?;
+
+        Ok(parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = S::solve(&parsed, part, &params);
+
+                PartResult {
+                    part,
+                    answer,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect())
+    }
+
+    fn time(&self, input: &str, params: &Table) -> Result<Timing> {
+        let params = read_params::<S>(params)?;
+        let start = Instant::now();
+        let parsed = S::load(input)?;
+        let parse = start.elapsed();
+
+        let parts = (1..=S::PARTS)
+            .map(|part| {
+                // don't let a part profit from the memo of an earlier run
+                let start = Instant::now();
+                S::solve(&parsed, part, &params)?;
+                Ok(start.elapsed())
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Timing { parse, parts })
+    }
+
+    fn check(&self, input: &str, params: &Table) -> Result<Vec<Assumption>> {
+        let params = read_params::<S>(params)?;
+        let parsed = S::load(input)?;
+
+        Ok(S::assumptions(&parsed, &params))
+    }
+}
+
+static DAYS_2022: &[&dyn Puzzle] = &[
+    &entry::<y2022_day01::Day01>(),
+    &entry::<y2022_day02::Day02>(),
+    &entry::<y2022_day03::Day03>(),
+    &entry::<y2022_day04::Day04>(),
+    &entry::<y2022_day05::Day05>(),
+    &entry::<y2022_day06::Day06>(),
+    &entry::<y2022_day07::Day07>(),
+    &entry::<y2022_day08::Day08>(),
+    &entry::<y2022_day09::Day09>(),
+    &entry::<y2022_day10::Day10>(),
+    &entry::<y2022_day11::Day11>(),
+    &entry::<y2022_day12::Day12>(),
+    &entry::<y2022_day13::Day13>(),
+    &entry::<y2022_day14::Day14>(),
+    &entry::<y2022_day15::Day15>(),
+    &entry::<y2022_day16::Day16>(),
+    &entry::<y2022_day17::Day17>(),
+    &entry::<y2022_day18::Day18>(),
+    &entry::<y2022_day19::Day19>(),
+    &entry::<y2022_day20::Day20>(),
+    &entry::<y2022_day21::Day21>(),
+    &entry::<y2022_day22::Day22>(),
+    &entry::<y2022_day23::Day23>(),
+    &entry::<y2022_day24::Day24>(),
+    &entry::<y2022_day25::Day25>(),
+];
+
+/// The registered days of an event.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [&'static dyn Puzzle],
+}
+
+impl Year {
+    const fn new(year: u16, days: &'static [&'static dyn Puzzle]) -> Self {
+        Year { year, days }
+    }
+}
+
+/// Oldest year first.
+pub static YEARS: &[Year] = &[Year::new(2022, DAYS_2022)];
+
+/// Year used when none is given, the most recent one.
+pub fn latest() -> u16 {
+    YEARS.last().map_or(2022, |y| y.year)
+}
+
+pub fn days(year: u16) -> std::result::Result<&'static [&'static dyn Puzzle], String> {
+    YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .map(|y| y.days)
+        .ok_or_else(|| format!("{} has no registered days", year))
+}
+
+pub fn get(year: u16, day: u8) -> std::result::Result<&'static dyn Puzzle, String> {
+    days(year)?
+        .iter()
+        .find(|d| d.day() == day)
+        .copied()
+        .ok_or_else(|| format!("day {} of {} is not registered", day, year))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SENSORS: &str = "\
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+
+    #[test]
+    fn years() {
+        assert_eq!(latest(), 2022);
+        assert_eq!(days(2022).unwrap().len(), 25);
+        assert_eq!(get(2022, 25).unwrap().day(), 25);
+        assert_eq!(days(2015).err().unwrap(), "2015 has no registered days");
+        assert_eq!(
+            get(2022, 26).err().unwrap(),
+            "day 26 of 2022 is not registered"
+        );
+    }
+
+    #[test]
+    fn params_from_table() {
+        let day15 = get(2022, 15).unwrap();
+        let params = "row = 10\nbounds = [0, 20]".parse::<Table>().unwrap();
+
+        let results = day15.run(SENSORS, &[1], &params).unwrap();
+        assert_eq!(results[0].answer, Ok(Answer::Integer(12)));
+    }
+
+    #[test]
+    fn invalid_params() {
+        let params = "rows = 10".parse::<Table>().unwrap();
+
+        let err = get(2022, 15)
+            .unwrap()
+            .run(SENSORS, &[1], &params)
+            .unwrap_err();
+        assert!(err.to_string().starts_with("day 15: invalid parameters: "));
+        assert!(get(2022, 1).unwrap().run("1", &[1], &params).is_err());
+    }
+}
@@ -0,0 +1,325 @@
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Instant,
+};
+
+use rustyline::{error::ReadlineError, DefaultEditor};
+use toml::Table;
+
+use crate::{
+    config, default_input, diagnostic, read_input,
+    registry::{self, Puzzle},
+};
+
+/// Lines entered in earlier sessions, relative to the working directory.
+const HISTORY: &str = ".advent-history";
+
+const HELP: &str = "\
+:year YEAR       choose the year, the day has to be chosen again
+:day DAY         choose a day and load inputs/YYYY/dayNN.txt if there is one
+:load PATH       load an input and the parameters next to it
+:set KEY=VALUE   change a parameter, the value is TOML like `bounds=[0, 20]`
+:unset KEY       go back to the default of a parameter
+:params          show the parameters
+:run [PART]      solve every part or a single one
+:time            show or hide how long solving took
+:quit            leave, as does Ctrl-D";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Year(u16),
+    Day(u8),
+    Load(PathBuf),
+    Set(String),
+    Unset(String),
+    Params,
+    Run(Option<u8>),
+    Time,
+    Help,
+    Quit,
+}
+
+fn number<T: FromStr>(name: &str, arg: &str) -> Result<T, String> {
+    arg.parse()
+        .map_err(|_| format!("{} expects a number, found {:?}", name, arg))
+}
+
+fn parse(line: &str) -> Result<Command, String> {
+    let line = line.trim();
+    let (name, arg) = line
+        .split_once(char::is_whitespace)
+        .map_or((line, ""), |(name, arg)| (name, arg.trim()));
+
+    match (name, arg) {
+        (":year", _) => Ok(Command::Year(number(name, arg)?)),
+        (":day", _) => Ok(Command::Day(number(name, arg)?)),
+        (":load" | ":set" | ":unset", "") => Err(format!("{} expects an argument", name)),
+        (":load", path) => Ok(Command::Load(PathBuf::from(path))),
+        (":set", o) => Ok(Command::Set(o.to_string())),
+        (":unset", key) => Ok(Command::Unset(key.to_string())),
+        (":run", "") => Ok(Command::Run(None)),
+        (":run", _) => Ok(Command::Run(Some(number(name, arg)?))),
+        (":params" | ":time" | ":help" | ":quit", a) if !a.is_empty() => {
+            Err(format!("{} takes no argument", name))
+        }
+        (":params", _) => Ok(Command::Params),
+        (":time", _) => Ok(Command::Time),
+        (":help", _) => Ok(Command::Help),
+        (":quit", _) => Ok(Command::Quit),
+        _ => Err(format!("unknown command {:?}, :help lists them", name)),
+    }
+}
+
+/// The chosen day, its input and parameters, kept between commands.
+struct Session {
+    year: u16,
+    puzzle: Option<&'static dyn Puzzle>,
+    input: Option<(PathBuf, String)>,
+    params: Table,
+    time: bool,
+}
+
+impl Session {
+    fn new(year: u16) -> Self {
+        Session {
+            year,
+            puzzle: None,
+            input: None,
+            params: Table::new(),
+            time: false,
+        }
+    }
+
+    fn prompt(&self) -> String {
+        match self.puzzle {
+            Some(puzzle) => format!("{} day {}> ", self.year, puzzle.day()),
+            None => format!("{}> ", self.year),
+        }
+    }
+
+    fn load(&mut self, path: &Path) -> Result<String, String> {
+        let input = read_input(path)?;
+        self.params = config::load(path)?;
+
+        let lines = input.lines().count();
+        self.input = Some((path.to_path_buf(), input));
+        Ok(format!("loaded {} ({} lines)", path.display(), lines))
+    }
+
+    fn execute(&mut self, command: Command) -> Result<String, String> {
+        match command {
+            Command::Year(year) => {
+                registry::days(year)?;
+                *self = Session {
+                    time: self.time,
+                    ..Session::new(year)
+                };
+                Ok(format!("{}, choose a day with :day", year))
+            }
+            Command::Day(day) => {
+                let puzzle = registry::get(self.year, day)?;
+                self.puzzle = Some(puzzle);
+                self.input = None;
+                self.params = Table::new();
+
+                let path = default_input(self.year, day);
+                if path.exists() {
+                    self.load(&path)
+                } else {
+                    Ok(format!("there is no {}, use :load", path.display()))
+                }
+            }
+            Command::Load(path) => self.load(&path),
+            Command::Set(o) => {
+                config::apply(&mut self.params, &[o])?;
+                self.execute(Command::Params)
+            }
+            Command::Unset(key) => match self.params.remove(&key) {
+                Some(_) => self.execute(Command::Params),
+                None => Err(format!("{} isn't set", key)),
+            },
+            Command::Params if self.params.is_empty() => Ok("(defaults)".to_string()),
+            Command::Params => Ok(self.params.to_string().trim_end().to_string()),
+            Command::Run(part) => self.run(part),
+            Command::Time => {
+                self.time = !self.time;
+                Ok(format!("timing {}", if self.time { "on" } else { "off" }))
+            }
+            Command::Help => Ok(HELP.to_string()),
+            Command::Quit => Ok(String::new()),
+        }
+    }
+
+    fn run(&self, part: Option<u8>) -> Result<String, String> {
+        let puzzle = self.puzzle.ok_or("choose a day with :day first")?;
+        let (path, input) = self
+            .input
+            .as_ref()
+            .ok_or("load an input with :load first")?;
+
+        let parts = match part {
+            Some(part) if (1..=puzzle.parts()).contains(&part) => vec![part],
+            Some(part) => return Err(format!("day {} has no part {}", puzzle.day(), part)),
+            None => (1..=puzzle.parts()).collect(),
+        };
+
+        // a panicking solution shouldn't end the session
+        let start = Instant::now();
+        let results = catch_unwind(AssertUnwindSafe(|| puzzle.run(input, &parts, &self.params)))
+            .map_err(|_| "panicked".to_string())?
+            .map_err(|e| diagnostic(&e, path, input))?;
+        let elapsed = start.elapsed();
+
+        let mut out = Vec::new();
+        for r in results {
+            let answer = match r.answer {
+                Ok(answer) => answer.to_string(),
+                Err(e) => format!("error: {}", diagnostic(&e, path, input)),
+            };
+            let time = match self.time {
+                true => format!(" ({:.1?})", r.elapsed),
+                false => String::new(),
+            };
+
+            let separator = if answer.contains('\n') { "\n" } else { " " };
+            out.push(format!("puzzle {}{}:{}{}", r.part, time, separator, answer));
+        }
+        if self.time {
+            out.push(format!("total {:.1?}", elapsed));
+        }
+
+        Ok(out.join("\n"))
+    }
+}
+
+/// Reads commands until `:quit` or the end of the input, errors don't end the session.
+pub fn run(year: u16, day: Option<u8>, input: Option<PathBuf>) -> Result<(), String> {
+    let mut session = Session::new(year);
+    let mut startup = Vec::new();
+    if let Some(day) = day {
+        startup.push(Command::Day(day));
+    }
+    if let Some(path) = input {
+        startup.push(Command::Load(path));
+    }
+    for command in startup {
+        match session.execute(command) {
+            Ok(text) => println!("{}", text),
+            Err(e) => eprintln!("error: {}", e),
+        }
+    }
+
+    let mut editor =
+        DefaultEditor::new().map_err(|e| format!("could not start the prompt: {}", e))?;
+    // there is no history before the first session
+    editor.load_history(HISTORY).ok();
+
+    println!(":help lists the commands");
+    loop {
+        let line = match editor.readline(&session.prompt()) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(format!("could not read the prompt: {}", e)),
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line.as_str()).ok();
+
+        match parse(&line) {
+            Ok(Command::Quit) => break,
+            Ok(command) => match session.execute(command) {
+                Ok(text) => println!("{}", text),
+                Err(e) => eprintln!("error: {}", e),
+            },
+            Err(e) => eprintln!("error: {}", e),
+        }
+    }
+
+    editor
+        .save_history(HISTORY)
+        .map_err(|e| format!("could not write {}: {}", HISTORY, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands() {
+        assert_eq!(parse(" :day 15 "), Ok(Command::Day(15)));
+        assert_eq!(
+            parse(":set bounds = [0, 20]"),
+            Ok(Command::Set("bounds = [0, 20]".to_string()))
+        );
+        assert_eq!(parse(":run"), Ok(Command::Run(None)));
+        assert_eq!(parse(":run 2"), Ok(Command::Run(Some(2))));
+        assert_eq!(
+            parse(":day fifteen"),
+            Err(":day expects a number, found \"fifteen\"".to_string())
+        );
+        assert_eq!(
+            parse(":time please"),
+            Err(":time takes no argument".to_string())
+        );
+        assert!(parse("run").is_err());
+    }
+
+    #[test]
+    fn rerun_with_params() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let mut session = Session::new(2022);
+
+        assert!(session.execute(Command::Run(None)).is_err());
+        session.execute(Command::Day(11)).unwrap();
+        session
+            .execute(Command::Load(root.join("examples/2022/day11/example.txt")))
+            .unwrap();
+        assert_eq!(
+            session.execute(Command::Run(None)).unwrap(),
+            "puzzle 1: 10605\npuzzle 2: 2713310158"
+        );
+
+        session
+            .execute(Command::Set("rounds=[20, 20]".to_string()))
+            .unwrap();
+        assert_eq!(
+            session.execute(Command::Run(Some(2))).unwrap(),
+            "puzzle 2: 10197"
+        );
+        assert_eq!(
+            session.execute(Command::Run(Some(3))),
+            Err("day 11 has no part 3".to_string())
+        );
+
+        session
+            .execute(Command::Unset("rounds".to_string()))
+            .unwrap();
+        session.execute(Command::Time).unwrap();
+        let timed = session.execute(Command::Run(Some(1))).unwrap();
+        assert!(timed.starts_with("puzzle 1 ("));
+        assert!(timed.contains("): 10605\ntotal "));
+    }
+
+    #[test]
+    fn example_params() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let mut session = Session::new(2022);
+
+        session.execute(Command::Day(15)).unwrap();
+        session
+            .execute(Command::Load(root.join("examples/2022/day15/example.txt")))
+            .unwrap();
+        assert_eq!(
+            session.execute(Command::Params).unwrap(),
+            "bounds = [0, 20]\nrow = 10"
+        );
+        assert_eq!(
+            session.execute(Command::Run(Some(1))).unwrap(),
+            "puzzle 1: 26"
+        );
+    }
+}
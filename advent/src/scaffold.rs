@@ -0,0 +1,254 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    path::{Path, PathBuf},
+};
+
+use crate::examples;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const EXAMPLE_TOML: &str = include_str!("../templates/example.toml.tmpl");
+
+/// Package of a day, `y2022_day01` lives in `2022/day01`.
+fn package(year: u16, day: u8) -> String {
+    format!("y{}_day{:02}", year, day)
+}
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    let example = examples::dir(Path::new(""), year, day).join("example.txt");
+
+    template
+        .replace("{{name}}", &package(year, day))
+        .replace("{{example}}", &example.display().to_string())
+        .replace("{{type}}", &format!("Day{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+// the comma separated items of a list, commas within brackets are part of an item
+fn split_items(list: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in list.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(&list[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&list[start..]);
+
+    items
+        .into_iter()
+        .map(str::trim)
+        .filter(|i| !i.is_empty())
+        .collect()
+}
+
+// adds `item` to the sorted list between `open` and `close`, one item per line
+fn add_to_list(text: &str, open: &str, close: &str, item: &str) -> Option<String> {
+    let start = text.find(open)? + open.len();
+    let end = start + text[start..].find(close)?;
+
+    let mut items = split_items(&text[start..end]);
+    items.push(item);
+    items.sort_unstable();
+    items.dedup();
+
+    let list = items
+        .iter()
+        .map(|i| format!("\n    {},", i))
+        .collect::<String>();
+    Some(format!("{}{}\n{}", &text[..start], list, &text[end..]))
+}
+
+// adds `line` to the sorted run of lines matching `is_item`
+fn insert_sorted(text: &str, line: &str, is_item: impl Fn(&str) -> bool) -> Option<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let first = lines.iter().position(|l| is_item(l))?;
+    let count = lines[first..].iter().take_while(|l| is_item(l)).count();
+    let at = first
+        + lines[first..first + count]
+            .iter()
+            .take_while(|&&l| l < line)
+            .count();
+
+    lines.insert(at, line);
+    Some(lines.join("\n") + "\n")
+}
+
+// the days of a year not seen before get a list of their own
+fn register(registry: &str, year: u16, day: u8) -> Option<String> {
+    let days = format!("static DAYS_{}: &[&dyn Puzzle] = &[", year);
+
+    let registry = if registry.contains(&days) {
+        registry.to_string()
+    } else {
+        let last = registry.rfind("static DAYS_")?;
+        let end = last + registry[last..].find("];")? + 2;
+        let registry = format!("{}\n\n{}];{}", &registry[..end], days, &registry[end..]);
+
+        let year = format!("Year::new({}, DAYS_{})", year, year);
+        add_to_list(&registry, "pub static YEARS: &[Year] = &[", "];", &year)?
+    };
+
+    let entry = format!("&entry::<{}::Day{:02}>()", package(year, day), day);
+    add_to_list(&registry, &days, "];", &entry)
+}
+
+/// Writes the crate of a new day and an empty example below the workspace `root`,
+/// and registers the crate with the workspace members, the dependencies of the
+/// runner and its registry.
+///
+/// Returns the files written, nothing is written if any of them can't be updated.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {}", day));
+    }
+    let member = format!("{}/day{:02}", year, day);
+    let dir = root.join(&member);
+    if dir.exists() {
+        return Err(format!("{} exists already", dir.display()));
+    }
+
+    let read = |path: PathBuf| {
+        read_to_string(&path)
+            .map(|text| (path.clone(), text))
+            .map_err(|e| format!("could not read {}: {}", path.display(), e))
+    };
+    let edit = |(path, text): (PathBuf, String), edit: &dyn Fn(&str) -> Option<String>| {
+        edit(&text)
+            .map(|text| (path.clone(), text))
+            .ok_or_else(|| format!("{} has no list to add {} to", path.display(), member))
+    };
+
+    let name = package(year, day);
+    let example_dir = examples::dir(root, year, day);
+    let files = vec![
+        (dir.join("Cargo.toml"), render(CARGO_TOML, year, day)),
+        (dir.join("src/lib.rs"), render(LIB_RS, year, day)),
+        (example_dir.join("example.txt"), String::new()),
+        (
+            example_dir.join("example.toml"),
+            render(EXAMPLE_TOML, year, day),
+        ),
+        edit(read(root.join("Cargo.toml"))?, &|text| {
+            add_to_list(text, "members = [", "]", &format!("\"{}\"", member))
+        })?,
+        edit(read(root.join("advent/Cargo.toml"))?, &|text| {
+            let dependency = format!("{} = {{ path = \"../{}\" }}", name, member);
+            insert_sorted(text, &dependency, |l| {
+                l.starts_with('y') && l.contains("_day")
+            })
+        })?,
+        edit(read(root.join("advent/src/registry.rs"))?, &|text| {
+            register(text, year, day)
+        })?,
+    ];
+
+    for dir in [dir.join("src"), example_dir] {
+        create_dir_all(&dir).map_err(|e| format!("could not create {}: {}", dir.display(), e))?;
+    }
+    for (path, text) in files.iter() {
+        write(path, text).map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::remove_dir_all;
+
+    use super::*;
+
+    const REGISTRY: &str = "\
+static DAYS_2022: &[&dyn Puzzle] = &[&entry::<y2022_day01::Day01>()];
+
+/// Oldest year first.
+pub static YEARS: &[Year] = &[Year::new(2022, DAYS_2022)];
+";
+
+    #[test]
+    fn lists() {
+        assert_eq!(
+            split_items("\n    Year::new(2022, DAYS_2022),\n    \"a\",\n"),
+            ["Year::new(2022, DAYS_2022)", "\"a\""]
+        );
+        assert_eq!(
+            add_to_list("m = [\"b\", \"d\"]\n", "m = [", "]", "\"c\"").unwrap(),
+            "m = [\n    \"b\",\n    \"c\",\n    \"d\",\n]\n"
+        );
+        assert_eq!(
+            insert_sorted("a\nday1\nday3\nz\n", "day2", |l| l.starts_with("day")).unwrap(),
+            "a\nday1\nday2\nday3\nz\n"
+        );
+        assert_eq!(add_to_list("[]", "m = [", "]", "\"a\""), None);
+    }
+
+    #[test]
+    fn new_year() {
+        let registry = register(REGISTRY, 2022, 2).unwrap();
+        assert!(registry.starts_with(
+            "static DAYS_2022: &[&dyn Puzzle] = &[\n    &entry::<y2022_day01::Day01>(),\n    &entry::<y2022_day02::Day02>(),\n];"
+        ));
+
+        let registry = register(&registry, 2023, 5).unwrap();
+        assert!(registry.contains(
+            "];\n\nstatic DAYS_2023: &[&dyn Puzzle] = &[\n    &entry::<y2023_day05::Day05>(),\n];\n\n/// Oldest year first."
+        ));
+        assert!(registry.ends_with(
+            "pub static YEARS: &[Year] = &[\n    Year::new(2022, DAYS_2022),\n    Year::new(2023, DAYS_2023),\n];\n"
+        ));
+    }
+
+    #[test]
+    fn new_crate() {
+        let root = std::env::temp_dir().join(format!("advent-new-{}", std::process::id()));
+        create_dir_all(root.join("advent/src")).unwrap();
+        write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"2022/day01\",\n    \"advent\",\n]\n",
+        )
+        .unwrap();
+        write(
+            root.join("advent/Cargo.toml"),
+            "[dependencies]\nclap = \"4.5\"\ny2022_day01 = { path = \"../2022/day01\" }\n",
+        )
+        .unwrap();
+        write(root.join("advent/src/registry.rs"), REGISTRY).unwrap();
+
+        let written = new_day(&root, 2022, 2).unwrap();
+        assert_eq!(written.len(), 7);
+
+        let lib = read_to_string(root.join("2022/day02/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day02 {\n    const DAY: u8 = 2;"));
+        assert!(lib.contains(
+            "    const INPUT: &str = include_str!(\"../../../examples/2022/day02/example.txt\");"
+        ));
+        assert!(root.join("examples/2022/day02/example.toml").exists());
+        assert!(read_to_string(root.join("2022/day02/Cargo.toml"))
+            .unwrap()
+            .contains("name = \"y2022_day02\""));
+        assert!(read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .ends_with("    \"2022/day01\",\n    \"2022/day02\",\n    \"advent\",\n]\n"));
+        assert!(read_to_string(root.join("advent/Cargo.toml"))
+            .unwrap()
+            .ends_with("y2022_day02 = { path = \"../2022/day02\" }\n"));
+        assert!(read_to_string(root.join("advent/src/registry.rs"))
+            .unwrap()
+            .contains("    &entry::<y2022_day02::Day02>(),\n];"));
+
+        assert_eq!(
+            new_day(&root, 2022, 2).unwrap_err(),
+            format!("{} exists already", root.join("2022/day02").display())
+        );
+        assert!(new_day(&root, 2022, 26).is_err());
+
+        remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    slice,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use serde_json::json;
+use tiny_http::{Header, Method, Request, Response, Server};
+use toml::Table;
+
+use crate::{all, config, output, registry};
+
+/// What a single request may use, a solve past the timeout is killed.
+pub struct Limits {
+    pub max_input: usize,
+    pub timeout: Duration,
+    pub jobs: usize,
+    /// Executable solving the inputs with `worker`, `advent` itself.
+    pub worker: PathBuf,
+}
+
+/// A request that can't be solved, answered with `{"error": ...}`.
+#[derive(Debug, PartialEq, Eq)]
+struct Rejected(u16, String);
+
+/// What to solve, from `POST /2022/day/15?part=1&param=row=10`.
+#[derive(Debug, PartialEq, Eq)]
+struct Query {
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    overrides: Vec<String>,
+}
+
+// percent-encoding of query strings, `+` is a space
+fn decode(text: &str) -> Result<String, Rejected> {
+    let invalid = || Rejected(400, format!("invalid encoding in {:?}", text));
+
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.bytes();
+    while let Some(b) = rest.next() {
+        bytes.push(match b {
+            b'+' => b' ',
+            b'%' => {
+                let hex = [
+                    rest.next().ok_or_else(invalid)?,
+                    rest.next().ok_or_else(invalid)?,
+                ];
+                let hex = std::str::from_utf8(&hex).map_err(|_| invalid())?;
+                u8::from_str_radix(hex, 16).map_err(|_| invalid())?
+            }
+            b => b,
+        });
+    }
+
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+fn parse_query(url: &str) -> Result<Query, Rejected> {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let not_found = || Rejected(404, format!("{} isn't a day, try /2022/day/1", path));
+
+    let (year, day) = match path.split('/').collect::<Vec<_>>()[..] {
+        ["", year, "day", day] => (
+            year.parse().map_err(|_| not_found())?,
+            day.parse().map_err(|_| not_found())?,
+        ),
+        _ => return Err(not_found()),
+    };
+
+    let mut part = None;
+    let mut overrides = Vec::new();
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let value = decode(value)?;
+        match decode(key)?.as_str() {
+            "part" => {
+                let n = value
+                    .parse()
+                    .map_err(|_| Rejected(400, format!("part {:?} isn't a number", value)))?;
+                part = Some(n);
+            }
+            "param" => overrides.push(value),
+            key => return Err(Rejected(400, format!("unknown query parameter {:?}", key))),
+        }
+    }
+
+    Ok(Query {
+        year,
+        day,
+        part,
+        overrides,
+    })
+}
+
+// the body up to the limit, chunked requests don't tell their length up front
+fn read_body(request: &mut Request, max_input: usize) -> Result<String, Rejected> {
+    let too_large = || Rejected(413, format!("inputs are limited to {} bytes", max_input));
+    if request.body_length().is_some_and(|n| n > max_input) {
+        return Err(too_large());
+    }
+
+    let mut body = Vec::new();
+    request
+        .as_reader()
+        .take(max_input as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|e| Rejected(400, format!("could not read the input: {}", e)))?;
+    if body.len() > max_input {
+        return Err(too_large());
+    }
+
+    String::from_utf8(body).map_err(|_| Rejected(400, "the input isn't UTF-8".to_string()))
+}
+
+/// Solves an input read from stdin and prints the answers as JSON, fails if a part fails.
+///
+/// `serve` runs it in a process of its own, so that it can be killed after the timeout.
+pub fn work(year: u16, day: u8, part: Option<u8>, overrides: &[String]) -> Result<(), String> {
+    let puzzle = registry::get(year, day)?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => (1..=puzzle.parts()).collect(),
+    };
+    let mut params = Table::new();
+    config::apply(&mut params, overrides)?;
+    let input =
+        io::read_to_string(io::stdin()).map_err(|e| format!("could not read the input: {}", e))?;
+
+    let outcome = all::solve_input(year, puzzle, &parts, Path::new("input"), &input, &params);
+    println!("{}", output::json(slice::from_ref(&outcome)));
+
+    if !outcome.is_ok() {
+        return Err(format!("day {} failed", day));
+    }
+    Ok(())
+}
+
+fn spawn_worker(query: &Query, input: &str, limits: &Limits) -> io::Result<Child> {
+    let mut command = Command::new(&limits.worker);
+    command.args(["worker", "--year", &query.year.to_string()]);
+    command.args(["--day", &query.day.to_string()]);
+    if let Some(part) = query.part {
+        command.args(["--part", &part.to_string()]);
+    }
+    for param in query.overrides.iter() {
+        command.args(["--param", param]);
+    }
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+    // a worker that fails early doesn't read all of its input, dropping stdin ends it
+    let stdin = child.stdin.take();
+    stdin
+        .expect("stdin is piped")
+        .write_all(input.as_bytes())
+        .ok();
+
+    Ok(child)
+}
+
+/// Solves the input in a worker process, which is killed after the timeout.
+fn run_worker(query: &Query, input: &str, limits: &Limits) -> Result<(u16, String), Rejected> {
+    let failed = |e: io::Error| Rejected(500, format!("could not run the worker: {}", e));
+
+    let mut child = spawn_worker(query, input, limits).map_err(failed)?;
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut body = String::new();
+        // nobody listens after the timeout
+        sender
+            .send(stdout.read_to_string(&mut body).map(|_| body))
+            .ok();
+    });
+
+    let body = match receiver.recv_timeout(limits.timeout) {
+        Ok(body) => body,
+        Err(_) => {
+            // killing only fails if the worker has just ended
+            child.kill().ok();
+            child.wait().ok();
+            return Err(Rejected(
+                504,
+                format!("day {} took longer than {:?}", query.day, limits.timeout),
+            ));
+        }
+    };
+    let status = child.wait().map_err(failed)?;
+    let body = body.map_err(failed)?;
+
+    // the worker exits with 1 after printing failed parts, panics exit with 101
+    match status.code() {
+        Some(0) => Ok((200, body)),
+        Some(1) if !body.is_empty() => Ok((422, body)),
+        Some(101) => Err(Rejected(500, format!("day {} panicked", query.day))),
+        _ => Err(Rejected(
+            500,
+            format!("the worker of day {} failed with {}", query.day, status),
+        )),
+    }
+}
+
+/// Solves a request in a worker of its own, the worker is busy until it ends or is killed.
+fn solve(
+    request: &mut Request,
+    limits: &Limits,
+    busy: &Arc<AtomicUsize>,
+) -> Result<(u16, String), Rejected> {
+    if *request.method() != Method::Post {
+        return Err(Rejected(405, "only POST is supported".to_string()));
+    }
+
+    let query = parse_query(request.url())?;
+    let puzzle = registry::get(query.year, query.day).map_err(|e| Rejected(404, e))?;
+    if let Some(part) = query.part.filter(|p| !(1..=puzzle.parts()).contains(p)) {
+        let message = format!("day {} has no part {}", query.day, part);
+        return Err(Rejected(404, message));
+    }
+    // the worker can't tell bad parameters from a failed solve
+    let mut params = Table::new();
+    config::apply(&mut params, &query.overrides).map_err(|e| Rejected(400, e))?;
+    let input = read_body(request, limits.max_input)?;
+
+    if busy.fetch_add(1, Ordering::SeqCst) >= limits.jobs {
+        busy.fetch_sub(1, Ordering::SeqCst);
+        return Err(Rejected(503, "every worker is busy".to_string()));
+    }
+
+    let solved = run_worker(&query, &input, limits);
+    busy.fetch_sub(1, Ordering::SeqCst);
+    solved
+}
+
+fn respond(mut request: Request, limits: &Limits, busy: &Arc<AtomicUsize>) {
+    let (status, body) = solve(&mut request, limits, busy)
+        .unwrap_or_else(|Rejected(status, e)| (status, json!({ "error": e }).to_string()));
+
+    let header =
+        Header::from_bytes("Content-Type", "application/json").expect("the header is always valid");
+    let response = Response::from_string(body)
+        .with_status_code(status)
+        .with_header(header);
+    // the client may be gone already
+    request.respond(response).ok();
+}
+
+/// Answers requests on their own threads until the server fails.
+pub fn serve(server: Server, limits: Limits) {
+    let limits = Arc::new(limits);
+    let busy = Arc::new(AtomicUsize::new(0));
+
+    for request in server.incoming_requests() {
+        let (limits, busy) = (Arc::clone(&limits), Arc::clone(&busy));
+        thread::spawn(move || respond(request, &limits, &busy));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queries() {
+        assert_eq!(
+            parse_query("/2022/day/15?part=1&param=bounds%3D%5B0%2C+20%5D&param=row=10"),
+            Ok(Query {
+                year: 2022,
+                day: 15,
+                part: Some(1),
+                overrides: vec!["bounds=[0, 20]".to_string(), "row=10".to_string()],
+            })
+        );
+        assert_eq!(parse_query("/2022/day/15").unwrap().part, None);
+        assert_eq!(parse_query("/2022/15").unwrap_err().0, 404);
+        assert_eq!(
+            parse_query("/2022/day/15?rows=10"),
+            Err(Rejected(
+                400,
+                "unknown query parameter \"rows\"".to_string()
+            ))
+        );
+        assert_eq!(parse_query("/2022/day/15?param=%4").unwrap_err().0, 400);
+    }
+}
@@ -0,0 +1,67 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+/// HTTP server on a local port answering a fixed list of requests, one connection each.
+pub struct Stub {
+    pub url: String,
+    requests: Receiver<String>,
+}
+
+impl Stub {
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, requests) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                    if line.trim_end().is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+
+                let response = format!(
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+                sender.send(request).unwrap();
+            }
+        });
+
+        Stub { url, requests }
+    }
+
+    /// The next request the stub answered, with headers and body.
+    pub fn request(&self) -> String {
+        self.requests.recv().unwrap()
+    }
+
+    /// Requests answered so far without waiting for more.
+    pub fn answered(&self) -> usize {
+        self.requests.try_iter().count()
+    }
+}
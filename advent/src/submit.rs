@@ -0,0 +1,365 @@
+use std::{
+    fmt::Display,
+    fs::{read_to_string, write},
+    io::ErrorKind,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+/// What the website thought of an answer, kept in the history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// The response page to a submission.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Response {
+    /// `wait` is the time until the next answer is accepted.
+    Answered {
+        verdict: Verdict,
+        wait: Duration,
+    },
+    TooRecent(Duration),
+    /// The part is already solved, or part 2 isn't unlocked yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+// the text of the <article> with the message, without any tags
+fn message(page: &str) -> String {
+    // the split leaves the rest of the opening tag at the start
+    let (mut in_tag, article) = match page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+    {
+        Some((article, _)) => (true, article),
+        None => (false, page),
+    };
+
+    let mut text = String::new();
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// "4m 43s" or "one minute"
+fn duration(text: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    let mut words = text.split_whitespace();
+
+    while let Some(word) = words.next() {
+        let digits = word
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(word.len());
+        let (number, unit) = match word.split_at(digits) {
+            ("", "one" | "a" | "an") => (1, words.next()?),
+            (n, "") => (n.parse::<u64>().ok()?, words.next()?),
+            (n, unit) => (n.parse::<u64>().ok()?, unit),
+        };
+
+        seconds += number
+            * match unit.trim_end_matches('s') {
+                "h" | "hour" => 3600,
+                "m" | "minute" => 60,
+                "" | "second" => 1,
+                _ => return None,
+            };
+    }
+
+    (seconds > 0).then(|| Duration::from_secs(seconds))
+}
+
+/// Reads the message of the answer page, which isn't meant for machines.
+pub fn parse_response(page: &str) -> Response {
+    let text = message(page);
+    let lower = text.to_lowercase();
+
+    // the closest `start` before `end`, "you have" shows up twice in the message
+    let between = |start: &str, end: &str| {
+        let (before, _) = lower.split_once(end)?;
+        let (_, middle) = before.rsplit_once(start)?;
+        duration(middle)
+    };
+
+    if lower.contains("that's the right answer") {
+        Response::Answered {
+            verdict: Verdict::Correct,
+            wait: Duration::ZERO,
+        }
+    } else if lower.contains("that's not the right answer") {
+        let verdict = if lower.contains("too high") {
+            Verdict::TooHigh
+        } else if lower.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        let wait = between("wait ", " before trying again").unwrap_or(Duration::from_secs(60));
+
+        Response::Answered { verdict, wait }
+    } else if lower.contains("you gave an answer too recently") {
+        Response::TooRecent(
+            between("you have ", " left to wait").unwrap_or(Duration::from_secs(60)),
+        )
+    } else if lower.contains("solving the right level") {
+        Response::WrongLevel
+    } else {
+        Response::Unknown(text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Submission {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: String,
+    verdict: Verdict,
+}
+
+/// Answers submitted so far, so wrong ones aren't sent twice.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    /// Unix time before which the website doesn't take answers.
+    #[serde(default)]
+    wait_until: u64,
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match read_to_string(path) {
+            Ok(text) => {
+                toml::from_str(&text).map_err(|e| format!("invalid {}: {}", path.display(), e))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(format!("could not read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let text = toml::to_string(self).expect("the history is always serializable");
+        write(path, text).map_err(|e| format!("could not write {}: {}", path.display(), e))
+    }
+
+    fn of(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| (s.year, s.day, s.part) == (year, day, part))
+    }
+
+    /// The verdict an answer is known to get, numbers are also compared to the
+    /// ones which were too high or too low.
+    pub fn known(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<Verdict> {
+        let number = answer.parse::<i128>().ok();
+
+        self.of(year, day, part).find_map(|s| {
+            let bound = s.answer.parse::<i128>().ok();
+            match (s.verdict, number, bound) {
+                _ if s.answer == answer => Some(s.verdict),
+                (Verdict::TooHigh, Some(n), Some(b)) if n >= b => Some(Verdict::TooHigh),
+                (Verdict::TooLow, Some(n), Some(b)) if n <= b => Some(Verdict::TooLow),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn solution(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.of(year, day, part)
+            .find(|s| s.verdict == Verdict::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    fn record(&mut self, year: u16, day: u8, part: u8, answer: &str, verdict: Verdict) {
+        self.submissions.push(Submission {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+}
+
+/// Submits an answer unless the history already knows the verdict.
+///
+/// Cooldowns of the website are waited out with `wait`, and the verdict is
+/// added to the history at `path`.
+pub fn submit(
+    client: &Client,
+    path: &Path,
+    (year, day, part): (u16, u8, u8),
+    answer: &str,
+    mut wait: impl FnMut(Duration),
+) -> Result<Verdict, String> {
+    let mut history = History::load(path)?;
+
+    if let Some(solution) = history.solution(year, day, part) {
+        return Err(format!(
+            "day {} part {} is already solved with {}",
+            day, part, solution
+        ));
+    }
+    if let Some(verdict) = history.known(year, day, part, answer) {
+        return Err(format!("{} is known to be {}", answer, verdict));
+    }
+
+    let remaining = history.wait_until.saturating_sub(now());
+    if remaining > 0 {
+        wait(Duration::from_secs(remaining));
+    }
+
+    // the cooldown may be longer than recorded, e.g. after answering in a browser
+    for _ in 0..3 {
+        match parse_response(&client.answer(year, day, part, answer)?) {
+            Response::Answered { verdict, wait } => {
+                history.record(year, day, part, answer, verdict);
+                history.wait_until = now() + wait.as_secs();
+                history.save(path)?;
+                return Ok(verdict);
+            }
+            Response::TooRecent(cooldown) => wait(cooldown),
+            Response::WrongLevel => {
+                return Err(format!(
+                    "day {} part {} is already solved or still locked",
+                    day, part
+                ))
+            }
+            Response::Unknown(text) => return Err(format!("unexpected response: {}", text)),
+        }
+    }
+
+    Err("the website keeps asking to wait, try again later".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::remove_file;
+
+    use super::*;
+    use crate::stub::Stub;
+
+    const CORRECT: &str = "<main><article><p>That's the right answer! You are <em>one gold star</em> closer to collecting enough star fruit.</p></article></main>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 43s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>";
+
+    #[test]
+    fn responses() {
+        assert_eq!(
+            parse_response(CORRECT),
+            Response::Answered {
+                verdict: Verdict::Correct,
+                wait: Duration::ZERO
+            }
+        );
+        assert_eq!(
+            parse_response(TOO_HIGH),
+            Response::Answered {
+                verdict: Verdict::TooHigh,
+                wait: Duration::from_secs(60)
+            }
+        );
+        assert_eq!(
+            parse_response("<article><p>That's not the right answer.  Please wait 5 minutes before trying again.</p></article>"),
+            Response::Answered {
+                verdict: Verdict::Wrong,
+                wait: Duration::from_secs(300)
+            }
+        );
+        assert_eq!(
+            parse_response(TOO_RECENT),
+            Response::TooRecent(Duration::from_secs(283))
+        );
+        assert_eq!(
+            parse_response("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            Response::WrongLevel
+        );
+        assert_eq!(
+            parse_response("<html><body>Please log in</body></html>"),
+            Response::Unknown("Please log in".to_string())
+        );
+    }
+
+    #[test]
+    fn known_answers() {
+        let mut history = History::default();
+        history.record(2022, 1, 1, "70000", Verdict::TooHigh);
+        history.record(2022, 1, 1, "100", Verdict::TooLow);
+        history.record(2022, 1, 1, "abc", Verdict::Wrong);
+
+        assert_eq!(history.known(2022, 1, 1, "80000"), Some(Verdict::TooHigh));
+        assert_eq!(history.known(2022, 1, 1, "99"), Some(Verdict::TooLow));
+        assert_eq!(history.known(2022, 1, 1, "abc"), Some(Verdict::Wrong));
+        assert_eq!(history.known(2022, 1, 1, "69000"), None);
+        assert_eq!(history.known(2022, 1, 2, "80000"), None);
+    }
+
+    #[test]
+    fn submit_and_record() {
+        let stub = Stub::serve(vec![(200, TOO_RECENT), (200, TOO_HIGH), (200, CORRECT)]);
+        let client = Client::new(&stub.url, "c0ffee");
+        let path = std::env::temp_dir().join(format!("advent-submit-{}.toml", std::process::id()));
+        let mut waits = Vec::new();
+
+        let verdict = submit(&client, &path, (2022, 1, 1), "70000", |d| waits.push(d));
+        assert_eq!(verdict, Ok(Verdict::TooHigh));
+        assert_eq!(waits, [Duration::from_secs(283)]);
+
+        let request = stub.request();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=1&answer=70000"));
+        stub.request();
+
+        // wrong answers aren't sent again, but the cooldown is kept for new ones
+        let err = submit(&client, &path, (2022, 1, 1), "75000", |d| waits.push(d));
+        assert_eq!(err, Err("75000 is known to be too high".to_string()));
+
+        let verdict = submit(&client, &path, (2022, 1, 1), "69000", |d| waits.push(d));
+        assert_eq!(verdict, Ok(Verdict::Correct));
+        assert_eq!(waits.len(), 2);
+        assert!(waits[1] <= Duration::from_secs(60));
+
+        let err = submit(&client, &path, (2022, 1, 1), "68000", |d| waits.push(d));
+        assert_eq!(
+            err,
+            Err("day 1 part 1 is already solved with 69000".to_string())
+        );
+
+        remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,147 @@
+use std::{
+    fs::metadata,
+    path::PathBuf,
+    thread::sleep,
+    time::{Duration, SystemTime},
+};
+
+use common::Answer;
+
+use crate::all::Outcome;
+
+/// Notices changes of files by polling, so it works the same on every platform.
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    stamps: Vec<Option<(SystemTime, u64)>>,
+}
+
+// a missing file has no stamp, so creating or deleting it is a change as well
+fn stamps(paths: &[PathBuf]) -> Vec<Option<(SystemTime, u64)>> {
+    paths
+        .iter()
+        .map(|p| {
+            metadata(p)
+                .ok()
+                .and_then(|m| Some((m.modified().ok()?, m.len())))
+        })
+        .collect()
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let stamps = stamps(&paths);
+        Watcher { paths, stamps }
+    }
+
+    /// Whether any of the files changed since the last call.
+    pub fn poll(&mut self) -> bool {
+        let stamps = stamps(&self.paths);
+        let changed = stamps != self.stamps;
+        self.stamps = stamps;
+        changed
+    }
+
+    /// Blocks until a change, and until the files stop changing for an `interval`,
+    /// as editors often save in several steps.
+    pub fn wait(&mut self, interval: Duration) {
+        while !self.poll() {
+            sleep(interval);
+        }
+        loop {
+            sleep(interval);
+            if !self.poll() {
+                break;
+            }
+        }
+    }
+}
+
+/// The answers of a run next to the ones of the run before.
+pub fn compare(previous: Option<&Outcome>, current: &Outcome) -> String {
+    let render = |answer: &Result<Answer, String>| match answer {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {}", e),
+    };
+
+    let mut out = vec![format!("Day {:02} ({:.1?})", current.day, current.elapsed)];
+    for p in current.parts.iter() {
+        let now = render(&p.answer);
+        let before = previous
+            .and_then(|o| o.parts.iter().find(|b| b.part == p.part))
+            .map(|b| render(&b.answer));
+
+        out.push(match before {
+            Some(before) if before == now => format!("puzzle {}: {} (unchanged)", p.part, now),
+            Some(before) if now.contains('\n') || before.contains('\n') => {
+                format!("puzzle {}: \n{}\nwas:\n{}", p.part, now, before)
+            }
+            Some(before) => format!("puzzle {}: {} (was {})", p.part, now, before),
+            None if now.contains('\n') => format!("puzzle {}: \n{}", p.part, now),
+            None => format!("puzzle {}: {}", p.part, now),
+        });
+    }
+
+    out.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{remove_file, write};
+
+    use super::*;
+    use crate::all::PartOutcome;
+
+    fn outcome(answers: &[Result<&str, &str>]) -> Outcome {
+        Outcome {
+            year: 2022,
+            day: 15,
+            parts: answers
+                .iter()
+                .enumerate()
+                .map(|(i, a)| PartOutcome {
+                    part: i as u8 + 1,
+                    answer: a.map(Answer::from).map_err(str::to_string),
+                    elapsed: Duration::ZERO,
+                })
+                .collect(),
+            elapsed: Duration::from_millis(3),
+        }
+    }
+
+    #[test]
+    fn answers_side_by_side() {
+        let first = outcome(&[Ok("26"), Ok("56000011")]);
+        assert_eq!(
+            compare(None, &first),
+            "Day 15 (3.0ms)\npuzzle 1: 26\npuzzle 2: 56000011"
+        );
+
+        let second = outcome(&[Ok("27"), Ok("56000011")]);
+        assert_eq!(
+            compare(Some(&first), &second),
+            "Day 15 (3.0ms)\npuzzle 1: 27 (was 26)\npuzzle 2: 56000011 (unchanged)"
+        );
+
+        let third = outcome(&[Err("day 15: no sensors"), Err("day 15: no sensors")]);
+        assert_eq!(
+            compare(Some(&second), &third).lines().nth(1),
+            Some("puzzle 1: error: day 15: no sensors (was 27)")
+        );
+    }
+
+    #[test]
+    fn notices_changes() {
+        let path = std::env::temp_dir().join(format!("advent-watch-{}.txt", std::process::id()));
+        write(&path, "1").unwrap();
+
+        let mut watcher = Watcher::new(vec![path.clone()]);
+        assert!(!watcher.poll());
+
+        write(&path, "12").unwrap();
+        assert!(watcher.poll());
+        assert!(!watcher.poll());
+
+        remove_file(&path).unwrap();
+        assert!(watcher.poll());
+    }
+}
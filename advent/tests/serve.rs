@@ -0,0 +1,128 @@
+//! The server solves inputs in `advent worker` processes, so it is tested
+//! through the binary instead of in the crate.
+
+use std::{
+    io::{BufRead, BufReader},
+    process::{Child, Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A running `advent serve`, killed when dropped.
+struct Server {
+    child: Child,
+    url: String,
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.child.kill().ok();
+        self.child.wait().ok();
+    }
+}
+
+fn start(args: &[&str]) -> Server {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_advent"))
+        .args(["serve", "--port", "0"])
+        .args(args)
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut line = String::new();
+    let stdout = child.stdout.take().unwrap();
+    BufReader::new(stdout).read_line(&mut line).unwrap();
+    let url = line
+        .trim()
+        .strip_prefix("listening on ")
+        .unwrap()
+        .to_string();
+
+    Server { child, url }
+}
+
+fn post(url: &str, body: &str) -> (u16, serde_json::Value) {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .http_status_as_error(false)
+        .build()
+        .into();
+    let mut response = agent.post(url).send(body).unwrap();
+    let text = response.body_mut().read_to_string().unwrap();
+    (
+        response.status().as_u16(),
+        serde_json::from_str(&text).unwrap(),
+    )
+}
+
+#[test]
+fn answers_and_limits() {
+    let server = start(&["--max-input", "1000", "--jobs", "2"]);
+    let url = &server.url;
+    let example = include_str!("../../examples/2022/day15/example.txt");
+
+    let (status, body) = post(&format!("{}/2022/day/15?part=1&param=row=10", url), example);
+    assert_eq!(status, 200);
+    assert_eq!(body[0]["answer"], 26);
+    assert!(body[0]["elapsed_ns"].is_u64());
+
+    let (status, body) = post(&format!("{}/2022/day/1", url), &"1\n".repeat(501));
+    assert_eq!(status, 413);
+    assert_eq!(body["error"], "inputs are limited to 1000 bytes");
+
+    let (status, body) = post(&format!("{}/2022/day/1", url), "1\nx\n");
+    assert_eq!(status, 422);
+    assert!(body[0]["error"].as_str().unwrap().contains("line 2"));
+
+    let (status, _) = post(&format!("{}/2022/day/15?param=row", url), example);
+    assert_eq!(status, 400);
+
+    let (status, _) = post(&format!("{}/2022/day/26", url), "");
+    assert_eq!(status, 404);
+}
+
+#[test]
+fn timeouts() {
+    let server = start(&["--timeout", "1", "--jobs", "1"]);
+    let url = &server.url;
+
+    // a sensor covering everything makes the search go through every row, close to
+    // a billion of them take seconds even in a release build
+    let sensor = "Sensor at x=0, y=0: closest beacon is at x=2000000000, y=0";
+    let slow = format!("{}/2022/day/15?part=2&param=bounds=[0,900000000]", url);
+    let start = Instant::now();
+    let (status, body) = post(&slow, sensor);
+    assert_eq!(status, 504);
+    assert_eq!(body["error"], "day 15 took longer than 1s");
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    // the worker is killed, so the only job is free again
+    let example = include_str!("../../examples/2022/day01/example.txt");
+    let (status, _) = post(&format!("{}/2022/day/1", url), example);
+    assert_eq!(status, 200);
+}
+
+#[test]
+fn concurrent() {
+    let server = start(&["--jobs", "8"]);
+    let example = include_str!("../../examples/2022/day16/example.txt");
+    // a cheaper valve changes the answer but not the valve names
+    let other = example.replace("rate=13;", "rate=3;");
+
+    let requests: Vec<_> = (0..8)
+        .map(|i| {
+            let (input, expected) = if i % 2 == 0 {
+                (example.to_string(), 1651)
+            } else {
+                (other.clone(), 1514)
+            };
+            let url = format!("{}/2022/day/16?part=1", server.url);
+            thread::spawn(move || (post(&url, &input), expected))
+        })
+        .collect();
+
+    for request in requests {
+        let ((status, body), expected) = request.join().unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(body[0]["answer"], expected);
+    }
+}
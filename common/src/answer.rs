@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+/// Result of a puzzle part, printed the way the puzzle website expects it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Rows of lit (`true`) and dark pixels, like the CRT of day 10.
+    Pixels(Vec<Vec<bool>>),
+}
+
+impl Answer {
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Pixels(rows) => {
+                for (y, row) in rows.iter().enumerate() {
+                    if y > 0 {
+                        writeln!(f)?;
+                    }
+                    for &lit in row {
+                        write!(f, "{}", if lit { '#' } else { '.' })?;
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n.into())
+                }
+            }
+        )+
+    };
+}
+
+from_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+// no `From<usize>` for i128 in std, but it always fits
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Integer(n as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Answer::Integer(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(13140usize).to_string(), "13140");
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+
+        let pixels = Answer::Pixels(vec![vec![true, false], vec![false, true]]);
+        assert_eq!(pixels.to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!(Answer::from(u64::MAX).as_integer(), Some(u64::MAX as i128));
+        assert_eq!(Answer::from("1").as_integer(), None);
+    }
+}
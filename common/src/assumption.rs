@@ -0,0 +1,27 @@
+/// A property of the input that a solution relies on without checking it while
+/// solving, an input breaking it ends in a panic or a wrong answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assumption {
+    /// What is assumed, like "the start is at (1, 0)".
+    pub text: String,
+    /// Why the input doesn't hold up to it.
+    pub result: std::result::Result<(), String>,
+}
+
+impl Assumption {
+    pub fn new(text: impl Into<String>, result: std::result::Result<(), String>) -> Self {
+        Assumption {
+            text: text.into(),
+            result,
+        }
+    }
+
+    /// An assumption that only needs a condition, the message tells what was found instead.
+    pub fn check(text: impl Into<String>, holds: bool, found: impl FnOnce() -> String) -> Self {
+        Assumption::new(text, if holds { Ok(()) } else { Err(found()) })
+    }
+
+    pub fn holds(&self) -> bool {
+        self.result.is_ok()
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt::Display;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input doesn't match the puzzle format, `line` and `column` start at 1.
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        expected: String,
+        found: String,
+    },
+    /// The input is well-formed, but the puzzle can't be solved for it.
+    Solve { day: u8, message: String },
+}
+
+impl Error {
+    /// Creates a parse error pointing at `at`, which has to be a slice of `input`.
+    ///
+    /// The found text is taken from the start of `at` up to the next whitespace.
+    pub fn parse(day: u8, input: &str, at: &str, expected: impl Into<String>) -> Self {
+        let offset = offset(input, at);
+        let (line, column) = location(input, offset);
+
+        let rest = &input[offset..];
+        let found = if rest.is_empty() {
+            "end of input".to_string()
+        } else if rest.starts_with(['\n', '\r']) {
+            "end of line".to_string()
+        } else {
+            // keep leading spaces, they are often part of the expected text
+            let start = rest.len() - rest.trim_start_matches(' ').len();
+            let end = rest[start..]
+                .find(char::is_whitespace)
+                .map_or(rest.len(), |i| start + i);
+            format!("{:?}", rest[..end].chars().take(20).collect::<String>())
+        };
+
+        Error::Parse {
+            day,
+            line,
+            column,
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    pub fn solve(day: u8, message: impl Into<String>) -> Self {
+        Error::Solve {
+            day,
+            message: message.into(),
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        match self {
+            Error::Parse { day, .. } | Error::Solve { day, .. } => *day,
+        }
+    }
+
+    /// Line and column of a parse error.
+    pub fn location(&self) -> Option<(usize, usize)> {
+        match self {
+            Error::Parse { line, column, .. } => Some((*line, *column)),
+            Error::Solve { .. } => None,
+        }
+    }
+
+    /// Converts the error of a nom parser which was run on `at`, a slice of `input`.
+    #[cfg(feature = "nom")]
+    pub fn nom(
+        day: u8,
+        input: &str,
+        err: nom::Err<nom::error::Error<&str>>,
+        expected: impl Into<String>,
+    ) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                Error::parse(day, input, e.input, expected)
+            }
+            nom::Err::Incomplete(_) => Error::parse(day, input, &input[input.len()..], expected),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse {
+                day,
+                line,
+                column,
+                expected,
+                found,
+            } => write!(
+                f,
+                "day {}, line {}, column {}: expected {}, found {}",
+                day, line, column, expected, found
+            ),
+            Error::Solve { day, message } => write!(f, "day {}: {}", day, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// byte offset of the subslice `at` inside `input`, clamped to the input
+fn offset(input: &str, at: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let pos = at.as_ptr() as usize;
+
+    if pos < start || pos > start + input.len() {
+        return input.len();
+    }
+
+    pos - start
+}
+
+fn location(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+move 1 from 2 to 1
+move x from 1 to 3
+move 2";
+
+    #[test]
+    fn location() {
+        let at = &INPUT[24..];
+        let err = Error::parse(5, INPUT, at, "a number");
+
+        assert_eq!(err.location(), Some((2, 6)));
+        assert_eq!(
+            err.to_string(),
+            "day 5, line 2, column 6: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn end_of_input() {
+        let at = &INPUT[INPUT.len()..];
+        let err = Error::parse(5, INPUT, at, "\" from \"");
+
+        assert_eq!(err.location(), Some((3, 7)));
+        assert_eq!(
+            err.to_string(),
+            "day 5, line 3, column 7: expected \" from \", found end of input"
+        );
+    }
+}
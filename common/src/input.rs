@@ -0,0 +1,43 @@
+use std::{borrow::Cow, fs, io, path::Path};
+
+/// Brings an input into the form the parsers expect: no byte order mark,
+/// `\n` line endings and no trailing whitespace at the end of the input.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+    if input.contains('\r') {
+        let input = input.replace("\r\n", "\n");
+        return Cow::Owned(input.trim_end().to_string());
+    }
+
+    Cow::Borrowed(input.trim_end())
+}
+
+/// Reads and normalizes an input file.
+pub fn read(path: impl AsRef<Path>) -> io::Result<String> {
+    let input = fs::read_to_string(path)?;
+
+    Ok(normalize(&input).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_endings() {
+        assert_eq!(normalize("1\r\n2\r\n\r\n3\r\n"), "1\n2\n\n3");
+        assert_eq!(normalize("\u{feff}1\n2\n"), "1\n2");
+    }
+
+    #[test]
+    fn leading_whitespace() {
+        // leading spaces can be part of a drawing
+        assert_eq!(normalize("    [D]\n[N] [C]\n"), "    [D]\n[N] [C]");
+    }
+
+    #[test]
+    fn unchanged() {
+        assert!(matches!(normalize("1\n2"), Cow::Borrowed("1\n2")));
+    }
+}
@@ -0,0 +1,12 @@
+mod answer;
+mod assumption;
+mod error;
+pub mod input;
+mod observe;
+mod solution;
+
+pub use answer::Answer;
+pub use assumption::Assumption;
+pub use error::{Error, Result};
+pub use observe::{observe, Observer, StepLimit};
+pub use solution::Solution;
@@ -0,0 +1,88 @@
+use std::ops::ControlFlow;
+
+use crate::{Error, Result};
+
+/// Gets to see the state of a simulation after every step or round.
+///
+/// Returning `ControlFlow::Break` ends the simulation early, which leaves it
+/// without an answer, see [`observe`].
+pub trait Observer<S: ?Sized> {
+    fn step(&mut self, step: usize, state: &S) -> ControlFlow<()>;
+}
+
+/// Nobody is watching, which is how the solutions run.
+impl<S: ?Sized> Observer<S> for () {
+    fn step(&mut self, _: usize, _: &S) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+}
+
+impl<S: ?Sized, F: FnMut(usize, &S) -> ControlFlow<()>> Observer<S> for F {
+    fn step(&mut self, step: usize, state: &S) -> ControlFlow<()> {
+        self(step, state)
+    }
+}
+
+/// Both observers see every step, the simulation stops as soon as one of them wants to.
+impl<S: ?Sized, A: Observer<S>, B: Observer<S>> Observer<S> for (A, B) {
+    fn step(&mut self, step: usize, state: &S) -> ControlFlow<()> {
+        self.0.step(step, state)?;
+        self.1.step(step, state)
+    }
+}
+
+/// Stops a simulation after the given number of steps.
+pub struct StepLimit(pub usize);
+
+impl<S: ?Sized> Observer<S> for StepLimit {
+    fn step(&mut self, step: usize, _: &S) -> ControlFlow<()> {
+        match step >= self.0 {
+            true => ControlFlow::Break(()),
+            false => ControlFlow::Continue(()),
+        }
+    }
+}
+
+/// Shows a state to the observer, the simulation of `day` fails if it was stopped.
+pub fn observe<S: ?Sized>(
+    day: u8,
+    observer: &mut impl Observer<S>,
+    step: usize,
+    state: &S,
+) -> Result<()> {
+    match observer.step(step, state) {
+        ControlFlow::Continue(()) => Ok(()),
+        ControlFlow::Break(()) => Err(Error::solve(day, format!("stopped after step {}", step))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // counts up to 10 unless it is stopped
+    fn count(observer: &mut impl Observer<u32>) -> Result<u32> {
+        let mut n = 0;
+        for step in 1..=10 {
+            n += 1;
+            observe(1, observer, step, &n)?;
+        }
+        Ok(n)
+    }
+
+    #[test]
+    fn observers() {
+        assert_eq!(count(&mut ()), Ok(10));
+
+        let mut seen = Vec::new();
+        let mut log = |_, &n: &u32| {
+            seen.push(n);
+            ControlFlow::Continue(())
+        };
+        assert_eq!(
+            count(&mut (&mut log, StepLimit(3))),
+            Err(Error::solve(1, "stopped after step 3"))
+        );
+        assert_eq!(seen, [1, 2, 3]);
+    }
+}
@@ -0,0 +1,93 @@
+use serde::de::DeserializeOwned;
+
+use crate::{input::normalize, Answer, Assumption, Error, Result};
+
+/// Common interface of the daily puzzles.
+///
+/// The input is parsed once and can then be shared by both parts.
+/// Puzzles which need extra arguments besides the input (e.g. the row
+/// of day 15) receive them through `Params`, whose default holds the
+/// values for the real puzzle input. The runner can read them from a
+/// config file, so fields missing there should fall back to the default.
+pub trait Solution {
+    const DAY: u8;
+    const PARTS: u8 = 2;
+
+    type Input;
+    type Params: Default + DeserializeOwned;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parses an input as it comes from a file, see [`normalize`].
+    fn load(input: &str) -> Result<Self::Input> {
+        Self::parse(&normalize(input))
+    }
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Answer>;
+
+    fn part2(_input: &Self::Input, _params: &Self::Params) -> Result<Answer> {
+        Err(Error::solve(Self::DAY, "there is no second part"))
+    }
+
+    /// Checks the properties of an input the parts rely on without checking them.
+    fn assumptions(_input: &Self::Input, _params: &Self::Params) -> Vec<Assumption> {
+        Vec::new()
+    }
+
+    /// Solves a single part, fails for parts the puzzle doesn't have.
+    fn solve(input: &Self::Input, part: u8, params: &Self::Params) -> Result<Answer> {
+        match part {
+            1 => Self::part1(input, params),
+            2 if Self::PARTS >= 2 => Self::part2(input, params),
+            _ => Err(Error::solve(
+                Self::DAY,
+                format!("there is no part {}", part),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Single;
+
+    impl Solution for Single {
+        const DAY: u8 = 25;
+        const PARTS: u8 = 1;
+
+        type Input = Vec<u32>;
+        type Params = ();
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .lines()
+                .map(|l| {
+                    l.parse()
+                        .map_err(|_| Error::parse(25, input, l, "a number"))
+                })
+                .collect()
+        }
+
+        fn part1(input: &Self::Input, _: &()) -> Result<Answer> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+    }
+
+    #[test]
+    fn solve() {
+        let input = Single::parse("1\n2\n3").unwrap();
+
+        assert_eq!(Single::solve(&input, 1, &()), Ok(Answer::Integer(6)));
+        assert!(Single::solve(&input, 2, &()).is_err());
+        assert!(Single::solve(&input, 0, &()).is_err());
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = Single::parse("1\n2\nthree").unwrap_err();
+
+        assert_eq!(err.location(), Some((3, 1)));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::Point2;
+
+/// The 4 orthogonal directions, north points up (towards lower `y`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// The 8 directions of a compass rose, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir4 {
+    /// Clockwise, starting north.
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    /// Quarter turn counterclockwise.
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Quarter turn clockwise.
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    /// A step of length 1 in the direction.
+    pub fn unit(self) -> Point2 {
+        match self {
+            Dir4::North => Point2::new(0, -1),
+            Dir4::East => Point2::new(1, 0),
+            Dir4::South => Point2::new(0, 1),
+            Dir4::West => Point2::new(-1, 0),
+        }
+    }
+}
+
+impl Dir8 {
+    /// Clockwise, starting north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// Eighth turn counterclockwise.
+    pub fn turn_left(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Eighth turn clockwise.
+    pub fn turn_right(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// A step in the direction, diagonals move in both axes.
+    pub fn unit(self) -> Point2 {
+        match self {
+            Dir8::North => Point2::new(0, -1),
+            Dir8::NorthEast => Point2::new(1, -1),
+            Dir8::East => Point2::new(1, 0),
+            Dir8::SouthEast => Point2::new(1, 1),
+            Dir8::South => Point2::new(0, 1),
+            Dir8::SouthWest => Point2::new(-1, 1),
+            Dir8::West => Point2::new(-1, 0),
+            Dir8::NorthWest => Point2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for d in Dir4::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_right(), d.opposite());
+            assert_eq!(d.opposite().unit(), -d.unit());
+        }
+        assert_eq!(Dir4::North.turn_left(), Dir4::West);
+        assert_eq!(Dir4::West.turn_right(), Dir4::North);
+
+        for d in Dir8::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.opposite().unit(), -d.unit());
+        }
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+    }
+
+    #[test]
+    fn units() {
+        assert_eq!(Dir4::South.unit(), Point2::new(0, 1));
+        assert_eq!(Dir8::from(Dir4::East), Dir8::East);
+        assert_eq!(
+            Dir8::NorthEast.unit(),
+            Dir8::North.unit() + Dir8::East.unit()
+        );
+    }
+}
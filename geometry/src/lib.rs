@@ -0,0 +1,5 @@
+mod dir;
+mod point;
+
+pub use dir::{Dir4, Dir8};
+pub use point::{Point2, Point3};
@@ -0,0 +1,238 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::{Dir4, Dir8};
+
+/// A position on a 2D grid, `y` grows downwards like the rows of the puzzle inputs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Number of king moves between the points.
+    pub fn chebyshev(self, other: Point2) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Sign of both coordinates, a step of at most 1 in each axis.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// North, east, south and west of the point.
+    pub fn neighbours4(self) -> [Point2; 4] {
+        Dir4::ALL.map(|d| self + d.unit())
+    }
+
+    /// All 8 surrounding points, clockwise starting north.
+    pub fn neighbours8(self) -> [Point2; 8] {
+        Dir8::ALL.map(|d| self + d.unit())
+    }
+
+    /// Componentwise minimum, e.g. for the corner of a bounding box.
+    pub fn min(self, other: Point2) -> Point2 {
+        Point2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Componentwise maximum.
+    pub fn max(self, other: Point2) -> Point2 {
+        Point2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Point3) -> i32 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn signum(self) -> Point3 {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// The 6 points sharing a face with this one.
+    pub fn neighbours6(self) -> [Point3; 6] {
+        [
+            Point3::new(-1, 0, 0),
+            Point3::new(1, 0, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, 0, -1),
+            Point3::new(0, 0, 1),
+        ]
+        .map(|d| self + d)
+    }
+
+    pub fn min(self, other: Point3) -> Point3 {
+        Point3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    pub fn max(self, other: Point3) -> Point3 {
+        Point3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+
+impl From<(i32, i32)> for Point2 {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point2::new(x, y)
+    }
+}
+
+impl From<(i32, i32, i32)> for Point3 {
+    fn from((x, y, z): (i32, i32, i32)) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+// componentwise arithmetic, the same for both point types
+macro_rules! impl_ops {
+    ($point:ident { $($c:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, rhs: $point) -> $point {
+                $point { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, rhs: $point) -> $point {
+                $point { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl Mul<i32> for $point {
+            type Output = $point;
+
+            fn mul(self, rhs: i32) -> $point {
+                $point { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($c: -self.$c),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, rhs: $point) {
+                $(self.$c += rhs.$c;)+
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, rhs: $point) {
+                $(self.$c -= rhs.$c;)+
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(-3, 4);
+
+        assert_eq!(a + b, Point2::new(-2, 6));
+        assert_eq!(a - b, Point2::new(4, -2));
+        assert_eq!(-a * 2, Point2::new(-2, -4));
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+
+        let mut c = Point3::new(1, 2, 3);
+        c += Point3::new(1, 1, 1);
+        assert_eq!(c, Point3::new(2, 3, 4));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(-3, 4);
+
+        assert_eq!(a.manhattan(b), 6);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point3::ORIGIN.manhattan(Point3::new(1, -2, 3)), 6);
+        assert_eq!(Point3::ORIGIN.chebyshev(Point3::new(1, -2, 3)), 3);
+    }
+
+    #[test]
+    fn neighbours() {
+        let p = Point2::new(5, 5);
+
+        assert_eq!(
+            p.neighbours4(),
+            [(5, 4), (6, 5), (5, 6), (4, 5)].map(Point2::from)
+        );
+        assert!(p.neighbours8().iter().all(|&n| p.chebyshev(n) == 1));
+        assert!(Point3::ORIGIN
+            .neighbours6()
+            .iter()
+            .all(|&n| Point3::ORIGIN.manhattan(n) == 1));
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use common::{Error, Result};
+
+use geometry::Point2;
+
+/// A rectangular grid stored row by row, positions are `(x, y)` starting top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Builds a grid from rows of different lengths, short rows are padded with `fill`.
+    pub fn from_rows(rows: Vec<Vec<T>>, fill: T) -> Self {
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let height = rows.len();
+
+        let mut cells = Vec::with_capacity(width * height);
+        for mut row in rows {
+            row.resize(width, fill.clone());
+            cells.extend(row);
+        }
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map with one row per line, all rows need the same width.
+    ///
+    /// `cell` converts a character, `None` is reported as a parse error with `expected`.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let width = input.lines().next().map_or(0, |l| l.chars().count());
+        if width == 0 {
+            return Err(Error::parse(day, input, input, expected));
+        }
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for l in input.lines() {
+            let mut count = 0;
+            for (i, c) in l.char_indices() {
+                if count == width {
+                    let expected = format!("{} columns", width);
+                    return Err(Error::parse(day, input, &l[i..], expected));
+                }
+
+                let value = cell(c).ok_or_else(|| Error::parse(day, input, &l[i..], expected))?;
+                cells.push(value);
+                count += 1;
+            }
+
+            if count < width {
+                let expected = format!("{} columns", width);
+                return Err(Error::parse(day, input, &l[l.len()..], expected));
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point2) -> bool {
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < self.width && (pos.y as usize) < self.height
+    }
+
+    /// Bounds checked access, positions outside of the grid give `None`.
+    pub fn get(&self, pos: Point2) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y as usize * self.width + pos.x as usize])
+    }
+
+    pub fn get_mut(&mut self, pos: Point2) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+
+        Some(&mut self.cells[pos.y as usize * self.width + pos.x as usize])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} out of bounds", x);
+
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell matching `predicate`, row by row.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        let i = self.cells.iter().position(predicate)?;
+
+        Some((i % self.width, i / self.width))
+    }
+
+    /// The 4-connected neighbours inside of the grid.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.inside(Point2::new(pos.0 as i32, pos.1 as i32).neighbours4())
+    }
+
+    /// The 8-connected neighbours inside of the grid.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.inside(Point2::new(pos.0 as i32, pos.1 as i32).neighbours8())
+    }
+
+    fn inside<const N: usize>(
+        &self,
+        points: [Point2; N],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        points
+            .into_iter()
+            .filter(|&p| self.contains(p))
+            .map(|p| (p.x as usize, p.y as usize))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(x < self.width, "column {} out of bounds", x);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(x < self.width, "column {} out of bounds", x);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+123
+456";
+
+    fn digits(input: &str) -> Result<Grid<u32>> {
+        Grid::parse(1, input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse() {
+        let grid = digits(INPUT).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(Point2::new(-1, 0)), None);
+        assert_eq!(grid.get(Point2::new(0, 2)), None);
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = digits("123\n4x6").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 2, column 2: expected a digit, found \"x6\""
+        );
+
+        let err = digits("123\n45").unwrap_err();
+        assert_eq!(err.location(), Some((2, 3)));
+
+        let err = digits("123\n4567").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 2, column 4: expected 3 columns, found \"7\""
+        );
+
+        assert!(digits("").is_err());
+    }
+
+    #[test]
+    fn views() {
+        let grid = digits(INPUT).unwrap();
+
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(
+            grid.column(2).rev().copied().collect::<Vec<_>>(),
+            vec![6, 3]
+        );
+        assert_eq!(grid.position(|&d| d == 5), Some((1, 1)));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits(INPUT).unwrap();
+
+        let n4 = grid.neighbours4((0, 0)).collect::<Vec<_>>();
+        assert_eq!(n4, vec![(1, 0), (0, 1)]);
+
+        let n8 = grid.neighbours8((1, 1)).collect::<Vec<_>>();
+        assert_eq!(n8, vec![(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)]);
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let grid = Grid::from_rows(vec![vec![1, 2], vec![3]], 0);
+
+        assert_eq!(grid.row(1), &[3, 0]);
+    }
+}
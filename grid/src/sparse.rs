@@ -0,0 +1,126 @@
+use std::{
+    collections::{hash_map, HashMap},
+    fmt::Display,
+};
+
+use geometry::Point2;
+
+/// An unbounded grid which only stores the occupied positions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Point2) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Point2) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Point2) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn insert(&mut self, pos: Point2, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Point2) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point2> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn iter(&self) -> hash_map::Iter<'_, Point2, T> {
+        self.cells.iter()
+    }
+
+    /// Top left and bottom right corner of the occupied area, both inclusive.
+    pub fn bounds(&self) -> Option<(Point2, Point2)> {
+        let mut positions = self.positions();
+        let first = positions.next()?;
+
+        Some(positions.fold((first, first), |(min, max), p| (min.min(p), max.max(p))))
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Point2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Point2, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point2, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter)
+    }
+}
+
+/// Renders the occupied area, empty positions are shown as '.'.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in min.y..=max.y {
+            if y > min.y {
+                writeln!(f)?;
+            }
+            for x in min.x..=max.x {
+                match self.get(Point2::new(x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds() {
+        let grid = [(Point2::new(2, -1), '#'), (Point2::new(-1, 1), 'o')]
+            .into_iter()
+            .collect::<SparseGrid<_>>();
+
+        assert_eq!(
+            grid.bounds(),
+            Some((Point2::new(-1, -1), Point2::new(2, 1)))
+        );
+        assert_eq!(grid.to_string(), "...#\n....\no...");
+        assert_eq!(SparseGrid::<char>::new().bounds(), None);
+    }
+}